@@ -153,7 +153,7 @@ impl TScene for Scene {
 
     fn on_event(&mut self, event: tin::TinEvent) {
         match event {
-            TinEvent::KeyDown(TinKey::D) => self.time_elapsed += 0.2,
+            TinEvent::KeyDown(event) if event.key == TinKey::D => self.time_elapsed += 0.2,
             _ => {}
         }
     }
@@ -1,8 +1,10 @@
 use crate::{
     backends::TBackend,
     scene::TScene,
+    space::TinCoordinateSpace,
     view::{TView, TinView},
-    CurrentBackend, UShort,
+    window::{TinWindowConfig, TinWindowId, TinWindowScene},
+    CurrentBackend, Double, UInt, UShort,
 };
 
 pub struct Tin<S>
@@ -11,6 +13,9 @@ where
 {
     pub(crate) view: TinView,
     pub(crate) target_fps: UShort,
+    pub(crate) fixed_timestep: Option<Double>,
+    pub(crate) coordinate_space: TinCoordinateSpace,
+    pub(crate) windows: Vec<TinWindowConfig>,
     phantom: std::marker::PhantomData<S>,
 }
 
@@ -22,6 +27,9 @@ where
         Tin {
             view: TinView::new("Default Title", crate::frame::TinFrame::default()),
             target_fps: 60,
+            fixed_timestep: None,
+            coordinate_space: TinCoordinateSpace::default(),
+            windows: Vec::new(),
             phantom: std::marker::PhantomData,
         }
     }
@@ -31,10 +39,57 @@ where
         self
     }
 
+    /// Limit how many frames run each second. 0 runs them as fast as the backend allows.
+    pub fn fps(mut self, fps: UShort) -> Self {
+        self.set_fps(fps);
+        self
+    }
+
+    /// Call `TScene::fixed_update` every `step` seconds, independently of the frame rate.
+    pub fn fixed_timestep(mut self, step: Double) -> Self {
+        self.fixed_timestep = Some(step);
+        self
+    }
+
+    /// The coordinate space the scene draws in. Points from the middle of the window with y up by default.
+    pub fn coordinate_space(mut self, space: TinCoordinateSpace) -> Self {
+        self.coordinate_space = space;
+        self
+    }
+
+    /**
+     Open another window that shows the app's scene, such as an output window for a projector.
+     The scene's `draw` is called once for each window, and `window::current_window` tells them apart.
+     Drawing goes to the window being drawn for.
+
+     Only the nannou backend opens more than one window. The luminance and software backends
+     ignore the windows added here, print a warning, and run the main window alone.
+    */
+    pub fn window(mut self, view: TinView) -> Self {
+        self.add_window(view, TinWindowScene::Shared);
+        self
+    }
+
+    /// Open another window with a scene of its own, such as a control window for operators. See `window` for which backends open it.
+    pub fn window_with_scene<W: TScene + 'static>(mut self, view: TinView) -> Self {
+        self.add_window(view, TinWindowScene::Own(crate::scene::boxed_scene::<W>));
+        self
+    }
+
+    fn add_window(&mut self, view: TinView, scene: TinWindowScene) {
+        let id = TinWindowId::new(self.windows.len() as UInt + 1);
+        self.windows.push(TinWindowConfig { id, view, scene });
+    }
+
     pub fn run(self) -> Result<(), ()> {
         {
             let frame = self.view.get_frame().clone();
-            crate::context::get_tin_mut().prepare(frame)
+            let mut tin = crate::context::get_tin_mut();
+            tin.prepare(frame);
+            tin.view = self.view.clone();
+            tin.clock.set_fixed_step(self.fixed_timestep);
+            tin.coordinate_space = self.coordinate_space;
+            tin.extra_windows = self.windows.clone();
         }
         CurrentBackend::run::<S>(self)
     }
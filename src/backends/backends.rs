@@ -1,15 +1,19 @@
-#[cfg(luminance_backend)]
+#[cfg(feature = "luminance_backend")]
 pub(crate) mod luminance;
 #[macro_use]
 pub(crate) mod nannou;
+#[cfg(feature = "image")]
+pub(crate) mod software;
 
 use crate::{
+    color::TinColor,
     frame::TinFrame,
     scene::TScene,
     shapes::{
-        ArcRenderer, EllipseRenderer, LineRenderer, PathRenderer, RectRenderer, TriangleRenderer,
+        ArcRenderer, EllipseRenderer, LineRenderer, PathRenderer, RectRenderer, TinRect,
+        TriangleRenderer,
     },
-    Tin,
+    Double, Tin,
 };
 
 #[cfg(feature = "text")]
@@ -43,6 +47,15 @@ where
     /// Handle rendering setup.
     fn prepare(&mut self, _frame: TinFrame) {}
 
+    /// Called with the drawing area's size in points whenever it is set, for renderers that place shapes by it.
+    fn resize(&mut self, _width: Double, _height: Double) {}
+
+    /// Only draw inside a rect given in points from the middle of the window, or everywhere for `None`.
+    fn set_clip(&mut self, _clip: Option<TinRect>) {}
+
+    /// Called when a background call is processed, for renderers that clear immediately.
+    fn background(&mut self, _color: TinColor) {}
+
     // MARK: rendering cycle
     fn prepare_for_update(&mut self);
     fn did_finish_update(&mut self);
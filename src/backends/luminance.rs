@@ -1,12 +1,12 @@
 pub(crate) mod arc;
 pub(crate) mod ellipse;
-#[cfg(image)]
+#[cfg(feature = "image")]
 pub(crate) mod image;
 pub(crate) mod line;
 pub(crate) mod path;
 pub(crate) mod rect;
 pub(crate) mod state;
-#[cfg(text)]
+#[cfg(feature = "text")]
 pub(crate) mod text;
 pub(crate) mod triangle;
 
@@ -15,6 +15,7 @@ use luminance::{
     context::GraphicsContext,
     pipeline::PipelineState,
     render_state::RenderState,
+    scissor::ScissorRegion,
     tess::{Mode, Tess},
 };
 use luminance_gl::gl33::GL33;
@@ -27,35 +28,48 @@ use crate::{
     backends::{TBackend, TinRenderer},
     brush::TBrush,
     color::TColor,
-    context::{get_tin, get_tin_mut, DrawState},
-    event::TinEvent,
+    context::{dispatch_event, get_tin, get_tin_mut, update_scene, DrawState},
+    event::{TinEvent, TinMouseButton, TinMouseEvent, TinScrollDelta},
+    key::{TinKeyEvent, TinModifiers},
     point::{TPoint, TinPoint},
     scene::TScene,
     shapes::*,
     vector2::TinVector2,
     vertex::*,
-    view::TView,
+    view::{TView, TinView, TinWindowPosition, TinWindowRequest},
 };
 pub(crate) struct LuminanceBackend {
-    pub shape_queue: Queue<TinShape>,
+    /// Shapes to draw this frame, each with the clip rect it is drawn inside.
+    pub shape_queue: Queue<(TinShape, Option<TinRect>)>,
+    clip: Option<TinRect>,
 
     path_started: bool,
-    path_vertices: Queue<TinVertex>,
+    path: TinPath,
+
+    /// Size of the drawing area in points.
+    size: (Double, Double),
+
+    #[cfg(feature = "text")]
+    glyphs: crate::text::TinGlyphCache,
 }
 
 impl LuminanceBackend {
+    /// Queue a shape given in points from the middle of the window, converted to the shader's normalized device coordinates.
     fn enqueue_shape(&mut self, mut points: Vec<TinVector2>, brush: TBrush, state: DrawState) {
+        let (half_width, half_height) = (self.size.0.max(1.0) / 2.0, self.size.1.max(1.0) / 2.0);
         for p in &mut points {
-            p.rotate(state.rotation)
+            let (x, y) = state.apply(p.x, p.y);
+            *p = TinVector2::from_xy(x / half_width, y / half_height);
         }
 
         let shape_queue = &mut self.shape_queue;
+        let clip = self.clip.clone();
         match brush {
-            TBrush::Fill(c) => shape_queue.push_back(make_shape_from_vector_vec(points, &c)),
-            TBrush::Stroke(c) => shape_queue.push_back(make_shape_from_vector_vec(points, &c)),
+            TBrush::Fill(c) => shape_queue.push_back((make_shape_from_vector_vec(points, &c), clip)),
+            TBrush::Stroke(c) => shape_queue.push_back((make_shape_from_vector_vec(points, &c), clip)),
             TBrush::FillAndStroke(f, s) => {
-                shape_queue.push_back(make_shape_from_vector_vec(points.clone(), &f));
-                shape_queue.push_back(make_shape_from_vector_vec(points, &s))
+                shape_queue.push_back((make_shape_from_vector_vec(points.clone(), &f), clip.clone()));
+                shape_queue.push_back((make_shape_from_vector_vec(points, &s), clip))
             }
             TBrush::Disabled => {}
         }
@@ -65,6 +79,14 @@ impl LuminanceBackend {
 impl TinRenderer for LuminanceBackend {
     // MARK: - Drawing methods
 
+    fn resize(&mut self, width: Double, height: Double) {
+        self.size = (width, height);
+    }
+
+    fn set_clip(&mut self, clip: Option<TinRect>) {
+        self.clip = clip;
+    }
+
     /** Call reset method in context that resets variables <br>
         Reset variables needed to draw:
         - buffer size should match the window size
@@ -77,12 +99,12 @@ impl TinRenderer for LuminanceBackend {
         self.shape_queue.clear();
 
         self.path_started = false;
-        self.path_vertices.clear();
+        self.path.clear();
 
         assert_eq!(self.shape_queue.len(), 0);
 
         assert_eq!(self.path_started, false);
-        assert_eq!(self.path_vertices.len(), 0);
+        assert!(self.path.is_empty());
     }
 
     fn did_finish_update(&mut self) {
@@ -93,11 +115,16 @@ impl TinRenderer for LuminanceBackend {
 impl TBackend for LuminanceBackend {
     fn new() -> Self {
         Self {
-            shape_queue: Queue::<TinShape>::new(),
+            shape_queue: Queue::new(),
+            clip: None,
 
             //delegate: TinContext::init(),// Probably need to change this when the context is fully implemented
             path_started: false,
-            path_vertices: Queue::<TinVertex>::new(),
+            path: TinPath::new(),
+            size: (1.0, 1.0),
+
+            #[cfg(feature = "text")]
+            glyphs: crate::text::TinGlyphCache::new(),
         }
     }
 
@@ -106,6 +133,9 @@ impl TBackend for LuminanceBackend {
         S: TScene,
     {
         eprintln!("LuminanceBackend::run()");
+        if !std::mem::take(&mut get_tin_mut().extra_windows).is_empty() {
+            eprintln!("WARNING: The luminance backend opens only the main window.");
+        }
         // Application logic here
         let view = &app.view;
 
@@ -127,13 +157,28 @@ impl TBackend for LuminanceBackend {
 
         let mut last_frame_time = Instant::now();
 
+        {
+            // The window can open at another size than asked for, such as when fullscreen.
+            let (width, height) = ctxt.window.get_size();
+            let (density, _) = ctxt.window.get_content_scale();
+            let mut tin = get_tin_mut();
+            tin.reset_size(width as Double, height as Double);
+            tin.pixel_density = density as Double;
+        }
+
         let mut scene = S::setup();
 
+        let mut last_update_time = Instant::now();
+
         'apploop: loop {
-            {
-                get_tin_mut().prepare_for_update();
+            let requests = std::mem::take(&mut get_tin_mut().window_requests);
+            for (_, request) in requests.into_iter().filter(|(window, _)| window.is_main()) {
+                apply_window_request(&mut ctxt.window, request);
             }
-            scene.update();
+
+            let now = Instant::now();
+            update_scene(&mut scene, now - last_update_time);
+            last_update_time = now;
 
             // Performance debugging display should be rendered here
             {
@@ -141,77 +186,33 @@ impl TBackend for LuminanceBackend {
                 tin.process_draw_calls();
                 tin.did_finish_update();
             }
+            if get_tin().quit_requested {
+                break 'apploop;
+            }
 
             // handle events
             ctxt.window.glfw.poll_events(); // Fill receiver with events
             for (_, event) in glfw::flush_messages(&events) {
-                match event {
+                match &event {
                     // End loop
-                    WindowEvent::Close | WindowEvent::Key(Key::Escape, _, Action::Release, _) => {
-                        break 'apploop
-                    }
-                    WindowEvent::Char(_) => {}
-                    WindowEvent::CharModifiers(_, _) => {}
+                    WindowEvent::Key(Key::Escape, _, Action::Release, _) => break 'apploop,
                     WindowEvent::FramebufferSize(..) => back_buffer = ctxt.back_buffer().unwrap(),
-
-                    WindowEvent::Key(k, _, Action::Press, _) => {
-                        scene.on_event(TinEvent::KeyDown(TinKey::from(k)))
-                    }
-                    WindowEvent::Key(k, _, Action::Release, _) => {
-                        scene.on_event(TinEvent::KeyUp(TinKey::from(k)))
-                    }
-                    WindowEvent::Key(_k, _, _, _) => {}
-
-                    WindowEvent::Pos(_, _) => {}
-                    WindowEvent::Size(_, _) => {}
-                    WindowEvent::Refresh => {}
-                    WindowEvent::Focus(_) => {}
-                    WindowEvent::Iconify(_) => {}
-
-                    WindowEvent::MouseButton(glfw::MouseButton::Button1, Action::Press, _) => {
-                        scene.on_event(TinEvent::MouseDown)
-                    }
-                    WindowEvent::MouseButton(glfw::MouseButton::Button1, Action::Release, _) => {
-                        scene.on_event(TinEvent::MouseUp)
-                    }
-                    WindowEvent::MouseButton(glfw::MouseButton::Button1, Action::Repeat, _) => {
-                        scene.on_event(TinEvent::MouseDown)
-                    }
-
-                    WindowEvent::MouseButton(glfw::MouseButton::Button2, Action::Press, _) => {
-                        scene.on_event(TinEvent::RightMouseDown)
-                    }
-                    WindowEvent::MouseButton(glfw::MouseButton::Button2, Action::Release, _) => {
-                        scene.on_event(TinEvent::RightMouseUp)
-                    }
-                    WindowEvent::MouseButton(glfw::MouseButton::Button2, Action::Repeat, _) => {
-                        scene.on_event(TinEvent::RightMouseDown)
-                    }
-
-                    WindowEvent::MouseButton(_, Action::Press, _) => {
-                        scene.on_event(TinEvent::OtherMouseDown)
-                    }
-                    WindowEvent::MouseButton(_, Action::Release, _) => {
-                        scene.on_event(TinEvent::OtherMouseUp)
-                    }
-                    WindowEvent::MouseButton(_, Action::Repeat, _) => {
-                        scene.on_event(TinEvent::OtherMouseDown)
-                    }
-
-                    WindowEvent::CursorPos(x, y) => {
-                        let mouse_pos = TinPoint::from_coords(x, y);
-                        get_tin_mut().mouse_moved(mouse_pos.clone());
-                        scene.on_event(TinEvent::MouseMoved(mouse_pos))
+                    _ => {}
+                }
+                let window_size = ctxt.window.get_size();
+                let cursor_position = ctxt.window.get_cursor_pos();
+                for tin_event in tin_events_from_glfw(event, window_size, cursor_position) {
+                    let should_quit = matches!(tin_event, TinEvent::WindowCloseRequested);
+                    dispatch_event(&mut scene, tin_event);
+                    if should_quit {
+                        break 'apploop;
                     }
-                    WindowEvent::CursorEnter(_) => {}
-                    WindowEvent::Scroll(_, _) => {}
-                    WindowEvent::FileDrop(_) => {}
-                    WindowEvent::Maximize(_) => {}
-                    WindowEvent::ContentScale(_, _) => {}
                 }
             }
 
-            let mut tesses: Queue<Tess<GL33, TinVertex>> = prepare_shapes_for_render(ctxt);
+            let mut tesses: Queue<(Tess<GL33, TinVertex>, Option<TinRect>)> = prepare_shapes_for_render(ctxt);
+            let window_size = ctxt.window.get_size();
+            let framebuffer_size = ctxt.window.get_framebuffer_size();
 
             // rendering code goes here
 
@@ -240,14 +241,16 @@ impl TBackend for LuminanceBackend {
                     |_, mut shd_gate| {
                         // Start shading
                         shd_gate.shade(&mut program, |_, _, mut rdr_gate| {
-                            // Start rendering
-                            rdr_gate.render(&RenderState::default(), |mut tess_gate| {
-                                while !tesses.is_empty() {
-                                    let shape = &tesses.pop_front().unwrap();
-                                    tess_gate.render(shape)?;
-                                }
-                                Ok(())
-                            })
+                            // Start rendering, with each shape's clip rect as the scissor region
+                            while let Some((shape, clip)) = tesses.pop_front() {
+                                let render_state = match clip {
+                                    Some(clip) => RenderState::default()
+                                        .set_scissor(scissor_region(&clip, window_size, framebuffer_size)),
+                                    None => RenderState::default(),
+                                };
+                                rdr_gate.render(&render_state, |mut tess_gate| tess_gate.render(&shape))?;
+                            }
+                            Ok(())
                         })
                     },
                 )
@@ -263,30 +266,122 @@ impl TBackend for LuminanceBackend {
                 break 'apploop;
             }
         }
+        scene.teardown();
         Ok(())
     }
 }
 
-fn produce_graphics_surface(view_ref: &impl TView) -> GlfwSurface {
-    let view_frame = view_ref.get_frame();
-
-    let view_title = view_ref.get_title();
+fn produce_graphics_surface(view: &TinView) -> GlfwSurface {
+    let view_frame = view.get_frame();
 
-    let win_opt = WindowOpt::default()
-        .set_dim(WindowDim::Windowed {
+    let dim = if view.is_fullscreen() {
+        WindowDim::Fullscreen
+    } else {
+        WindowDim::Windowed {
             width: view_frame.get_width(),
             height: view_frame.get_height(),
-        })
-        .set_cursor_mode(luminance_windowing::CursorMode::Visible);
+        }
+    };
+    let cursor_mode = if view.is_cursor_visible() {
+        luminance_windowing::CursorMode::Visible
+    } else {
+        luminance_windowing::CursorMode::Invisible
+    };
+    let win_opt = WindowOpt::default().set_dim(dim).set_cursor_mode(cursor_mode);
+
+    let mut surface =
+        GlfwSurface::new_gl33(view.get_title(), win_opt).expect("GlfwSurface could not be built");
+
+    // The window options only cover size and cursor, so the rest is set on the window.
+    let window = &mut surface.context.window;
+    window.set_resizable(view.is_resizable());
+    window.set_decorated(view.has_decorations());
+    window.set_floating(view.is_always_on_top());
+    move_window(window, view.get_position());
+    surface
+}
 
-    GlfwSurface::new_gl33(view_title, win_opt).expect("GlfwSurface could not be built")
+fn move_window(window: &mut glfw::Window, position: TinWindowPosition) {
+    let (width, height) = window.get_size();
+    let mut glfw = window.glfw.clone();
+    let target = glfw.with_primary_monitor(|_, monitor| {
+        let monitor = monitor?;
+        let mode = monitor.get_video_mode()?;
+        position.resolve(
+            monitor.get_pos(),
+            (mode.width, mode.height),
+            (width as UInt, height as UInt),
+        )
+    });
+    if let Some((x, y)) = target {
+        window.set_pos(x, y);
+    }
 }
 
-fn prepare_shapes_for_render(context: &mut GL33Context) -> Queue<Tess<GL33, TinVertex>> {
-    let mut tesses: Queue<Tess<GL33, TinVertex>> = Queue::new();
+fn apply_window_request(window: &mut glfw::Window, request: TinWindowRequest) {
+    match request {
+        TinWindowRequest::Title(title) => window.set_title(&title),
+        TinWindowRequest::Size(width, height) => window.set_size(width as Int, height as Int),
+        TinWindowRequest::Position(position) => move_window(window, position),
+        TinWindowRequest::Fullscreen(fullscreen) => {
+            let (x, y) = window.get_pos();
+            let (width, height) = window.get_size();
+            let mut glfw = window.glfw.clone();
+            glfw.with_primary_monitor(|_, monitor| match (fullscreen, monitor) {
+                (true, Some(monitor)) => window.set_monitor(
+                    glfw::WindowMode::FullScreen(monitor),
+                    0,
+                    0,
+                    width as u32,
+                    height as u32,
+                    None,
+                ),
+                _ => window.set_monitor(
+                    glfw::WindowMode::Windowed,
+                    x,
+                    y,
+                    width as u32,
+                    height as u32,
+                    None,
+                ),
+            });
+        }
+        TinWindowRequest::CursorVisible(visible) => window.set_cursor_mode(if visible {
+            glfw::CursorMode::Normal
+        } else {
+            glfw::CursorMode::Hidden
+        }),
+        TinWindowRequest::AlwaysOnTop(always_on_top) => window.set_floating(always_on_top),
+    }
+}
+
+/**
+ A clip rect in points from the middle of the window, as a scissor region in framebuffer pixels from the bottom left.
+ On HiDPI screens the framebuffer has more pixels than the window has points.
+*/
+fn scissor_region(clip: &TinRect, window_size: (i32, i32), framebuffer_size: (i32, i32)) -> ScissorRegion {
+    let density = if window_size.0 > 0 {
+        framebuffer_size.0 as Double / window_size.0 as Double
+    } else {
+        1.0
+    };
+    let left = clip.center.get_x() - clip.get_width() / 2.0 + window_size.0 as Double / 2.0;
+    let bottom = clip.center.get_y() - clip.get_height() / 2.0 + window_size.1 as Double / 2.0;
+    ScissorRegion {
+        x: (left * density).max(0.0).round() as u32,
+        y: (bottom * density).max(0.0).round() as u32,
+        width: (clip.get_width() * density).round() as u32,
+        height: (clip.get_height() * density).round() as u32,
+    }
+}
+
+fn prepare_shapes_for_render(
+    context: &mut GL33Context,
+) -> Queue<(Tess<GL33, TinVertex>, Option<TinRect>)> {
+    let mut tesses: Queue<(Tess<GL33, TinVertex>, Option<TinRect>)> = Queue::new();
     let shapes = &mut get_tin_mut().render.shape_queue;
     while !shapes.is_empty() {
-        let shape = shapes.pop_front().unwrap();
+        let (shape, clip) = shapes.pop_front().unwrap();
 
         let vertex_count = shape.get_vertices().len();
         let draw_mode: Mode;
@@ -304,131 +399,218 @@ fn prepare_shapes_for_render(context: &mut GL33Context) -> Queue<Tess<GL33, TinV
             .set_mode(draw_mode)
             .build()
             .expect("Could not build tesselation.");
-        tesses.push_back(shape_tess);
+        tesses.push_back((shape_tess, clip));
     }
     tesses
 }
 
+impl From<glfw::Modifiers> for TinModifiers {
+    fn from(mods: glfw::Modifiers) -> Self {
+        let mut modifiers = TinModifiers::NONE;
+        if mods.contains(glfw::Modifiers::Shift) {
+            modifiers.insert(TinModifiers::SHIFT);
+        }
+        if mods.contains(glfw::Modifiers::Control) {
+            modifiers.insert(TinModifiers::CONTROL);
+        }
+        if mods.contains(glfw::Modifiers::Alt) {
+            modifiers.insert(TinModifiers::ALT);
+        }
+        if mods.contains(glfw::Modifiers::Super) {
+            modifiers.insert(TinModifiers::LOGO);
+        }
+        modifiers
+    }
+}
+
+impl From<glfw::MouseButton> for TinMouseButton {
+    fn from(button: glfw::MouseButton) -> Self {
+        match button {
+            glfw::MouseButton::Button1 => TinMouseButton::Left,
+            glfw::MouseButton::Button2 => TinMouseButton::Right,
+            glfw::MouseButton::Button3 => TinMouseButton::Middle,
+            other => TinMouseButton::Other(other as i32 as UShort),
+        }
+    }
+}
+
+/**
+ Converts a glfw event into Tin events. Cursor positions are moved from window pixels with y pointing down
+ to the middle of the window with y pointing up, using the window size. A file drop becomes one event per file.
+*/
+fn tin_events_from_glfw(
+    event: WindowEvent,
+    window_size: (i32, i32),
+    cursor_position: (f64, f64),
+) -> Vec<TinEvent> {
+    let (width, height) = (window_size.0 as Double, window_size.1 as Double);
+    let centered = |x: f64, y: f64| TinPoint::from_coords(x - width / 2.0, height / 2.0 - y);
+    let mouse_event = |button: glfw::MouseButton, mods: glfw::Modifiers| TinMouseEvent {
+        button: TinMouseButton::from(button),
+        position: centered(cursor_position.0, cursor_position.1),
+        modifiers: TinModifiers::from(mods),
+    };
+    let tin_event = match event {
+        WindowEvent::Key(k, scan_code, action, mods) => {
+            let key_event = TinKeyEvent {
+                key: TinKey::from(k),
+                scan_code: scan_code as UInt,
+                modifiers: TinModifiers::from(mods),
+                is_repeat: action == Action::Repeat,
+            };
+            match action {
+                Action::Release => TinEvent::KeyUp(key_event),
+                _ => TinEvent::KeyDown(key_event),
+            }
+        }
+        WindowEvent::Char(c) => TinEvent::CharacterInput(c),
+
+        WindowEvent::MouseButton(b, Action::Release, mods) => TinEvent::MouseUp(mouse_event(b, mods)),
+        WindowEvent::MouseButton(b, _, mods) => TinEvent::MouseDown(mouse_event(b, mods)),
+        WindowEvent::CursorPos(x, y) => TinEvent::MouseMoved(centered(x, y)),
+        WindowEvent::CursorEnter(true) => TinEvent::MouseEntered,
+        WindowEvent::CursorEnter(false) => TinEvent::MouseExited,
+        WindowEvent::Scroll(x, y) => TinEvent::MouseWheel(TinScrollDelta::Lines(x, y)),
+
+        WindowEvent::Size(w, h) => TinEvent::WindowResized(w.max(0) as UInt, h.max(0) as UInt),
+        WindowEvent::Pos(x, y) => TinEvent::WindowMoved(x, y),
+        WindowEvent::ContentScale(x_scale, _) => TinEvent::WindowPixelDensityChanged(x_scale as Double),
+        WindowEvent::Focus(true) => TinEvent::WindowFocused,
+        WindowEvent::Focus(false) => TinEvent::WindowUnfocused,
+        WindowEvent::Close => TinEvent::WindowCloseRequested,
+        WindowEvent::FileDrop(paths) => {
+            return paths.into_iter().map(TinEvent::FileDropped).collect()
+        }
+        _ => TinEvent::Unknown,
+    };
+    vec![tin_event]
+}
+
 use glfw::Key;
 impl From<glfw::Key> for TinKey {
     fn from(k: glfw::Key) -> Self {
         match k {
-            Key::A => return TinKey::A,
-            Key::B => return TinKey::B,
-            Key::C => return TinKey::C,
-            Key::D => return TinKey::D,
-            Key::E => return TinKey::E,
-            Key::F => return TinKey::F,
-            Key::G => return TinKey::G,
-            Key::H => return TinKey::H,
-            Key::I => return TinKey::I,
-            Key::J => return TinKey::J,
-            Key::K => return TinKey::K,
-            Key::L => return TinKey::L,
-            Key::M => return TinKey::M,
-            Key::N => return TinKey::N,
-            Key::O => return TinKey::O,
-            Key::P => return TinKey::P,
-            Key::Q => return TinKey::Q,
-            Key::R => return TinKey::R,
-            Key::S => return TinKey::S,
-            Key::T => return TinKey::T,
-            Key::U => return TinKey::U,
-            Key::V => return TinKey::V,
-            Key::W => return TinKey::W,
-            Key::X => return TinKey::X,
-            Key::Y => return TinKey::Y,
-            Key::Z => return TinKey::Z,
-            Key::Num1 => return TinKey::One,
-            Key::Num2 => return TinKey::Two,
-            Key::Num3 => return TinKey::Three,
-            Key::Num4 => return TinKey::Four,
-            Key::Num5 => return TinKey::Five,
-            Key::Num6 => return TinKey::Six,
-            Key::Num7 => return TinKey::Seven,
-            Key::Num8 => return TinKey::Eight,
-            Key::Num9 => return TinKey::Nine,
-            Key::Num0 => return TinKey::Zero,
-            Key::Kp0 => return TinKey::Zero,
-            Key::Kp1 => return TinKey::One,
-            Key::Kp2 => return TinKey::Two,
-            Key::Kp3 => return TinKey::Three,
-            Key::Kp4 => return TinKey::Four,
-            Key::Kp5 => return TinKey::Five,
-            Key::Kp6 => return TinKey::Six,
-            Key::Kp7 => return TinKey::Seven,
-            Key::Kp8 => return TinKey::Eight,
-            Key::Kp9 => return TinKey::Nine,
-            Key::Escape => return TinKey::Escape,
-            Key::F1 => return TinKey::F1,
-            Key::F2 => return TinKey::F2,
-            Key::F3 => return TinKey::F3,
-            Key::F4 => return TinKey::F4,
-            Key::F5 => return TinKey::F5,
-            Key::F6 => return TinKey::F6,
-            Key::F7 => return TinKey::F7,
-            Key::F8 => return TinKey::F8,
-            Key::F9 => return TinKey::F9,
-            Key::F10 => return TinKey::F10,
-            Key::F11 => return TinKey::F11,
-            Key::F12 => return TinKey::F12,
-            Key::F13 => return TinKey::F13,
-            Key::F14 => return TinKey::F14,
-            Key::F15 => return TinKey::F15,
-            Key::F16 => return TinKey::F16,
-            Key::F17 => return TinKey::F17,
-            Key::F18 => return TinKey::F18,
-            Key::F19 => return TinKey::F19,
-            Key::F20 => return TinKey::F20,
-            Key::F21 => return TinKey::F21,
-            Key::F22 => return TinKey::F22,
-            Key::F23 => return TinKey::F23,
-            Key::F24 => return TinKey::F24,
-            Key::PrintScreen => return TinKey::Snapshot,
-            Key::ScrollLock => return TinKey::Scroll,
-            Key::Pause => return TinKey::Pause,
-            Key::Insert => return TinKey::Insert,
-            Key::Home => return TinKey::Home,
-            Key::Delete => return TinKey::Delete,
-            Key::End => return TinKey::End,
-            Key::PageDown => return TinKey::PageDown,
-            Key::PageUp => return TinKey::PageUp,
-            Key::Left => return TinKey::Left,
-            Key::Up => return TinKey::Up,
-            Key::Right => return TinKey::Right,
-            Key::Down => return TinKey::Down,
-            Key::Backspace => return TinKey::Backspace,
-            Key::Enter => return TinKey::Enter,
-            Key::Space => return TinKey::Space,
-            Key::NumLock => return TinKey::NumLock,
-            Key::Apostrophe => return TinKey::Apostrophe,
-            Key::Backslash => return TinKey::Backslash,
-            Key::Comma => return TinKey::Comma,
-            Key::Equal => return TinKey::Equals,
-            Key::LeftAlt => return TinKey::LAlt,
-            Key::LeftBracket => return TinKey::LBracket,
-            Key::LeftControl => return TinKey::LControl,
-            Key::LeftShift => return TinKey::LShift,
-            Key::Minus => return TinKey::Minus,
-            Key::Period => return TinKey::Period,
-            Key::RightAlt => return TinKey::RAlt,
-            Key::RightBracket => return TinKey::RBracket,
-            Key::RightControl => return TinKey::RControl,
-            Key::RightShift => return TinKey::RShift,
-            Key::Semicolon => return TinKey::Semicolon,
-            Key::Slash => return TinKey::Slash,
-            Key::Tab => return TinKey::Tab,
-            Key::GraveAccent => return TinKey::GraveAccent,
-            Key::CapsLock => return TinKey::CapsLock,
-            Key::KpDecimal => return TinKey::NumpadDecimal,
-            Key::KpDivide => return TinKey::NumpadDivide,
-            Key::KpMultiply => return TinKey::NumpadMultiply,
-            Key::KpSubtract => return TinKey::NumpadSubtract,
-            Key::KpAdd => return TinKey::NumpadAdd,
-            Key::KpEnter => return TinKey::NumpadEnter,
-            Key::KpEqual => return TinKey::NumpadEquals,
-            Key::Unknown => return TinKey::Unknown,
-            _ => TinKey::Unknown,
+            Key::A => TinKey::A,
+            Key::B => TinKey::B,
+            Key::C => TinKey::C,
+            Key::D => TinKey::D,
+            Key::E => TinKey::E,
+            Key::F => TinKey::F,
+            Key::G => TinKey::G,
+            Key::H => TinKey::H,
+            Key::I => TinKey::I,
+            Key::J => TinKey::J,
+            Key::K => TinKey::K,
+            Key::L => TinKey::L,
+            Key::M => TinKey::M,
+            Key::N => TinKey::N,
+            Key::O => TinKey::O,
+            Key::P => TinKey::P,
+            Key::Q => TinKey::Q,
+            Key::R => TinKey::R,
+            Key::S => TinKey::S,
+            Key::T => TinKey::T,
+            Key::U => TinKey::U,
+            Key::V => TinKey::V,
+            Key::W => TinKey::W,
+            Key::X => TinKey::X,
+            Key::Y => TinKey::Y,
+            Key::Z => TinKey::Z,
+            Key::Num0 => TinKey::Zero,
+            Key::Num1 => TinKey::One,
+            Key::Num2 => TinKey::Two,
+            Key::Num3 => TinKey::Three,
+            Key::Num4 => TinKey::Four,
+            Key::Num5 => TinKey::Five,
+            Key::Num6 => TinKey::Six,
+            Key::Num7 => TinKey::Seven,
+            Key::Num8 => TinKey::Eight,
+            Key::Num9 => TinKey::Nine,
+            Key::Kp0 => TinKey::Numpad0,
+            Key::Kp1 => TinKey::Numpad1,
+            Key::Kp2 => TinKey::Numpad2,
+            Key::Kp3 => TinKey::Numpad3,
+            Key::Kp4 => TinKey::Numpad4,
+            Key::Kp5 => TinKey::Numpad5,
+            Key::Kp6 => TinKey::Numpad6,
+            Key::Kp7 => TinKey::Numpad7,
+            Key::Kp8 => TinKey::Numpad8,
+            Key::Kp9 => TinKey::Numpad9,
+            Key::Escape => TinKey::Escape,
+            Key::F1 => TinKey::F1,
+            Key::F2 => TinKey::F2,
+            Key::F3 => TinKey::F3,
+            Key::F4 => TinKey::F4,
+            Key::F5 => TinKey::F5,
+            Key::F6 => TinKey::F6,
+            Key::F7 => TinKey::F7,
+            Key::F8 => TinKey::F8,
+            Key::F9 => TinKey::F9,
+            Key::F10 => TinKey::F10,
+            Key::F11 => TinKey::F11,
+            Key::F12 => TinKey::F12,
+            Key::F13 => TinKey::F13,
+            Key::F14 => TinKey::F14,
+            Key::F15 => TinKey::F15,
+            Key::F16 => TinKey::F16,
+            Key::F17 => TinKey::F17,
+            Key::F18 => TinKey::F18,
+            Key::F19 => TinKey::F19,
+            Key::F20 => TinKey::F20,
+            Key::F21 => TinKey::F21,
+            Key::F22 => TinKey::F22,
+            Key::F23 => TinKey::F23,
+            Key::F24 => TinKey::F24,
+            Key::F25 => TinKey::F25,
+            Key::PrintScreen => TinKey::Snapshot,
+            Key::ScrollLock => TinKey::Scroll,
+            Key::Pause => TinKey::Pause,
+            Key::Insert => TinKey::Insert,
+            Key::Home => TinKey::Home,
+            Key::Delete => TinKey::Delete,
+            Key::End => TinKey::End,
+            Key::PageDown => TinKey::PageDown,
+            Key::PageUp => TinKey::PageUp,
+            Key::Left => TinKey::Left,
+            Key::Up => TinKey::Up,
+            Key::Right => TinKey::Right,
+            Key::Down => TinKey::Down,
+            Key::Backspace => TinKey::Backspace,
+            Key::Enter => TinKey::Enter,
+            Key::Space => TinKey::Space,
+            Key::NumLock => TinKey::NumLock,
+            Key::Apostrophe => TinKey::Apostrophe,
+            Key::Backslash => TinKey::Backslash,
+            Key::Comma => TinKey::Comma,
+            Key::Equal => TinKey::Equals,
+            Key::LeftAlt => TinKey::LAlt,
+            Key::LeftBracket => TinKey::LBracket,
+            Key::LeftControl => TinKey::LControl,
+            Key::LeftShift => TinKey::LShift,
+            Key::LeftSuper => TinKey::LSuper,
+            Key::Minus => TinKey::Minus,
+            Key::Period => TinKey::Period,
+            Key::RightAlt => TinKey::RAlt,
+            Key::RightBracket => TinKey::RBracket,
+            Key::RightControl => TinKey::RControl,
+            Key::RightShift => TinKey::RShift,
+            Key::RightSuper => TinKey::RSuper,
+            Key::Menu => TinKey::Menu,
+            Key::Semicolon => TinKey::Semicolon,
+            Key::Slash => TinKey::Slash,
+            Key::Tab => TinKey::Tab,
+            Key::GraveAccent => TinKey::GraveAccent,
+            Key::CapsLock => TinKey::CapsLock,
+            Key::World1 => TinKey::World1,
+            Key::World2 => TinKey::World2,
+            Key::KpDecimal => TinKey::NumpadDecimal,
+            Key::KpDivide => TinKey::NumpadDivide,
+            Key::KpMultiply => TinKey::NumpadMultiply,
+            Key::KpSubtract => TinKey::NumpadSubtract,
+            Key::KpAdd => TinKey::NumpadAdd,
+            Key::KpEnter => TinKey::NumpadEnter,
+            Key::KpEqual => TinKey::NumpadEquals,
+            Key::Unknown => TinKey::Unknown,
         }
     }
 }
@@ -1,9 +1,16 @@
+use std::f64::consts::TAU;
+
 use crate::{
     backends::{luminance::LuminanceBackend, EllipseRenderer},
     context::DrawState,
+    point::TPoint,
+    vector2::TinVector2,
     Double,
 };
 
+/// Number of straight segments used to approximate a full ellipse.
+const ELLIPSE_SEGMENTS: usize = 64;
+
 impl EllipseRenderer for LuminanceBackend {
     fn ellipse(
         &mut self,
@@ -13,6 +20,16 @@ impl EllipseRenderer for LuminanceBackend {
         brush: crate::brush::TBrush,
         state: DrawState,
     ) {
-        todo!("Ellipse render for LuminanceBackend not implemented");
+        // The last point repeats the first to close the outline, as for rects.
+        let points: Vec<TinVector2> = (0..=ELLIPSE_SEGMENTS)
+            .map(|i| {
+                let theta = TAU * i as Double / ELLIPSE_SEGMENTS as Double;
+                TinVector2::from_xy(
+                    center.get_x() + w / 2.0 * theta.cos(),
+                    center.get_y() + h / 2.0 * theta.sin(),
+                )
+            })
+            .collect();
+        self.enqueue_shape(points, brush, state);
     }
 }
@@ -1,19 +1,27 @@
+use std::sync::Once;
+
 use crate::{
     backends::{luminance::LuminanceBackend, ImageRenderer},
-    image::TinImage,
+    image::TinImageHandle,
     Double,
 };
 
+/// Makes sure the missing image support is reported once rather than every frame.
+static WARN_UNSUPPORTED: Once = Once::new();
+
 impl ImageRenderer for LuminanceBackend {
+    /// The luminance backend has no texture pipeline yet, so images are not drawn.
     fn image_with_size_and_resize(
         &mut self,
-        image: &TinImage,
-        center: crate::point::TinPoint,
-        width: Double,
-        height: Double,
-        resize: bool,
-        state: crate::context::DrawState,
+        _image: &TinImageHandle,
+        _center: crate::point::TinPoint,
+        _width: Double,
+        _height: Double,
+        _resize: bool,
+        _state: crate::context::DrawState,
     ) {
-        todo!("LuminanceBackend::image_with_size_and_resize() not implemented");
+        WARN_UNSUPPORTED.call_once(|| {
+            eprintln!("WARNING: The luminance backend can't draw images yet.");
+        });
     }
 }
@@ -1,32 +1,74 @@
 use crate::{
-    backends::{
-        luminance::{make_shape_from_vertex_vec, LuminanceBackend},
-        PathRenderer,
-    },
-    point::TPoint,
-    vertex::TinVertex,
+    backends::{luminance::LuminanceBackend, PathRenderer},
+    brush::TBrush,
+    context::DrawState,
+    point::{TPoint, TinPoint},
+    shapes::{even_odd_spans, LineRenderer, RectRenderer, TinRect, PATH_CURVE_STEPS},
+    Double,
 };
 
 impl PathRenderer for LuminanceBackend {
     fn path_begin(&mut self) {
         self.path_started = true;
+        self.path.clear();
     }
 
     fn path_vertex(&mut self, at_point: &impl TPoint) {
-        todo!("path_vertex method in LuminanceBackend not supported yet");
+        self.path.vertex(at_point.get_x(), at_point.get_y());
     }
 
     fn path_add_curve(&mut self, to: &impl TPoint, control1: &impl TPoint, control2: &impl TPoint) {
-        todo!("path_add_curve method in LuminanceBackend not supported yet");
+        self.path.add_curve(to, control1, control2);
     }
 
-    fn path_end(&mut self) {
+    fn path_contour(&mut self) {
+        self.path.begin_contour();
+    }
+
+    fn path_end(&mut self, brush: TBrush, state: DrawState) {
         self.path_started = false;
-        let mut verts: Vec<TinVertex> = Vec::new();
-        for v in &mut self.path_vertices {
-            verts.push(*v)
+        let contours: Vec<Vec<(Double, Double)>> = self
+            .path
+            .flatten(PATH_CURVE_STEPS)
+            .into_iter()
+            .map(|contour| contour.into_iter().map(|p| (p.x, p.y)).collect())
+            .collect();
+        self.path.clear();
+
+        let (fill, stroke) = match brush {
+            TBrush::Fill(f) => (Some(f), None),
+            TBrush::Stroke(s) => (None, Some(s)),
+            TBrush::FillAndStroke(f, s) => (Some(f), Some(s)),
+            TBrush::Disabled => (None, None),
+        };
+
+        // Without a tessellator, the fill is drawn as one thin rect per inside span of each unit-tall row.
+        if let Some(color) = fill {
+            let points = contours.iter().flatten();
+            let min_y = points.clone().map(|p| p.1).fold(Double::INFINITY, Double::min);
+            let max_y = points.map(|p| p.1).fold(Double::NEG_INFINITY, Double::max);
+            let mut y = min_y.floor();
+            while y < max_y {
+                for (start, end) in even_odd_spans(&contours, y + 0.5) {
+                    let span = TinRect::from_dimensions((start + end) / 2.0, y + 0.5, end - start, 1.0);
+                    self.rect_with_tinrect(&span, TBrush::Fill(color), state);
+                }
+                y += 1.0;
+            }
+        }
+        if let Some(color) = stroke {
+            for contour in &contours {
+                for i in 0..contour.len() {
+                    let (a, b) = (contour[i], contour[(i + 1) % contour.len()]);
+                    self.line(
+                        TinPoint::from_coords(a.0, a.1),
+                        TinPoint::from_coords(b.0, b.1),
+                        1.0,
+                        TBrush::Stroke(color),
+                        state,
+                    );
+                }
+            }
         }
-        self.shape_queue
-            .push_back(make_shape_from_vertex_vec(verts));
     }
 }
@@ -1,7 +1,11 @@
 use crate::{
     backends::luminance::LuminanceBackend,
-    point::TinPoint,
+    brush::TBrush,
+    color::TinColor,
+    point::{TPoint, TinPoint},
+    shapes::{RectRenderer, TinRect},
     text::{TextRenderer, TinFont},
+    Double,
 };
 
 impl TextRenderer for LuminanceBackend {
@@ -10,8 +14,24 @@ impl TextRenderer for LuminanceBackend {
         message: &String,
         font: &TinFont,
         center: TinPoint,
+        color: TinColor,
         state: crate::context::DrawState,
     ) {
-        todo!("text method in LuminanceBackend not supported yet");
+        // Without a texture pipeline, each run of covered pixels in a row is drawn as a thin rect.
+        let bitmap = font.rasterize(message, &mut self.glyphs);
+        let left = center.get_x() + bitmap.left;
+        let top = center.get_y() + bitmap.top;
+        for row in 0..bitmap.height {
+            for (start, end) in bitmap.spans(row) {
+                let width = (end - start) as Double;
+                let span = TinRect::from_dimensions(
+                    left + start as Double + width / 2.0,
+                    top - row as Double - 0.5,
+                    width,
+                    1.0,
+                );
+                self.rect_with_tinrect(&span, TBrush::Fill(color), state);
+            }
+        }
     }
 }
@@ -15,30 +15,58 @@ use ::nannou::prelude::*;
 use crate::{
     backends::{TBackend, TinRenderer},
     color::*,
-    context::{get_tin, get_tin_mut},
-    event::TinEvent,
+    context::{
+        dispatch_event, dispatch_window_event, draw_shared_window, get_tin, get_tin_mut,
+        update_scene, update_window_scene,
+    },
+    event::{TinEvent, TinMouseButton, TinMouseEvent, TinScrollDelta},
+    key::{TinKeyEvent, TinModifiers},
     point::TPoint,
     point::TinPoint,
     scene::TScene,
     vector2::TinVector2,
-    vertex::TinVertex,
-    view::TView,
-    Double, Float, TColor, Tin,
+    view::{TView, TinView, TinWindowPosition, TinWindowRequest},
+    window::{TinWindowId, TinWindowScene},
+    Double, Float, Int, TColor, Tin, UInt,
 };
 
-use ::std::collections::VecDeque as Queue;
+thread_local! {
+    /**
+     What the window being drawn is rendered with, presented by `view`. It is kept here rather than in the
+     backend because a Draw can't leave the thread it was made on, while the context is shared by every thread.
+    */
+    static DRAW: Draw = Draw::new();
+}
 
 pub struct NannouBackend {
-    // draw: std::sync::Mutex<Draw>,
     current_background_color: TinColor,
     pub use_layer: bool,
 
-    path_vertices: Queue<TinVertex>,
+    path: crate::shapes::TinPath,
+    clip: Option<crate::shapes::TinRect>,
+
+    /// The GPU device of the main window, which images are uploaded to.
+    #[cfg(feature = "image")]
+    pub(crate) device_queue_pair: Option<std::sync::Arc<nannou::wgpu::DeviceQueuePair>>,
+    #[cfg(feature = "image")]
+    textures: crate::image::TinTextureCache<nannou::wgpu::Texture>,
+
+    #[cfg(feature = "text")]
+    fonts: std::collections::HashMap<crate::ULong, Option<nannou::text::Font>>,
 }
 
 impl NannouBackend {
     fn get_draw(&self) -> Draw {
-        nannou::Draw::new()
+        let draw = DRAW.with(|draw| draw.clone());
+        match &self.clip {
+            Some(clip) => draw.scissor(nannou::geom::Rect::from_x_y_w_h(
+                clip.center.get_x() as Float,
+                clip.center.get_y() as Float,
+                clip.get_width() as Float,
+                clip.get_height() as Float,
+            )),
+            None => draw,
+        }
     }
 }
 
@@ -105,12 +133,16 @@ impl TinRenderer for NannouBackend {
         eprintln!("NannouBackend::prepare_for_update()");
         self.current_background_color = DEFAULT_COLOR_BACKGROUND;
         self.use_layer = false;
-        self.path_vertices.clear();
+        self.path.clear();
     }
 
     fn did_finish_update(&mut self) {
         eprintln!("NannouBackend::did_finish_update")
     }
+
+    fn set_clip(&mut self, clip: Option<crate::shapes::TinRect>) {
+        self.clip = clip;
+    }
 }
 
 impl TBackend for NannouBackend {
@@ -120,7 +152,16 @@ impl TBackend for NannouBackend {
             current_background_color: DEFAULT_COLOR_BACKGROUND,
             use_layer: false,
 
-            path_vertices: Queue::<TinVertex>::new(),
+            path: crate::shapes::TinPath::new(),
+            clip: None,
+
+            #[cfg(feature = "image")]
+            device_queue_pair: None,
+            #[cfg(feature = "image")]
+            textures: crate::image::TinTextureCache::new(),
+
+            #[cfg(feature = "text")]
+            fonts: std::collections::HashMap::new(),
         }
     }
 
@@ -129,39 +170,152 @@ impl TBackend for NannouBackend {
         S: TScene,
     {
         eprintln!("NannouBackend::run()");
-        fn model<S>(app: &App) -> S
+        fn model<S>(app: &App) -> NannouModel<S>
         where
             S: TScene + 'static,
         {
+            let view = get_tin().view.clone();
+
             // Create a new window! Store the ID so we can refer to it later.
-            let _window = app
-                .new_window()
-                .size(512, 512)
-                .title("nannou")
+            let main_window = window_builder(app, &view)
                 // Handle events related to the window and update the model if necessary
-                .event(move |_app: &App, scene: &mut S, event: WindowEvent| {
+                .event(move |_app: &App, model: &mut NannouModel<S>, event: WindowEvent| {
                     // handle events
-                    if let Closed = event {
+                    let tin_event = match tin_event_from(_app, event) {
+                        Some(tin_event) => tin_event,
+                        None => return,
+                    };
+                    let should_quit = matches!(tin_event, TinEvent::WindowCloseRequested);
+                    dispatch_event(&mut model.scene, tin_event);
+                    if should_quit {
                         _app.quit();
-                        return;
                     }
-                    let mouse_pos_point: TinPoint = TinPoint::from(_app.mouse.position());
-                    get_tin_mut().mouse_moved(mouse_pos_point);
-                    scene.on_event(TinEvent::from(event));
+                })
+                .raw_event(move |_app: &App, model: &mut NannouModel<S>, event: &RawWindowEvent| {
+                    match event {
+                        RawWindowEvent::KeyboardInput { input, .. } => {
+                            let tin_event = tin_key_event_from(_app, input);
+                            dispatch_event(&mut model.scene, tin_event);
+                        }
+                        RawWindowEvent::ScaleFactorChanged { scale_factor, .. } => {
+                            let tin_event = TinEvent::WindowPixelDensityChanged(*scale_factor as Double);
+                            dispatch_event(&mut model.scene, tin_event);
+                        }
+                        _ => {}
+                    }
                 }) // The function that will be called when the window receives events.
                 .build()
                 .unwrap();
-            return S::setup();
+
+            // Position and cursor can only be set once the window exists.
+            if let Some(window) = app.window(main_window) {
+                window.set_cursor_visible(view.is_cursor_visible());
+                move_window(&window, view.get_position());
+
+                // The window can open at another size than asked for, such as when fullscreen.
+                let (width, height) = window.inner_size_points();
+                let mut tin = get_tin_mut();
+                tin.reset_size(width as Double, height as Double);
+                tin.pixel_density = window.scale_factor() as Double;
+                // Only nannou's own renderer uploads textures; a headless build renders in software instead.
+                #[cfg(all(feature = "image", not(feature = "headless")))]
+                {
+                    tin.render.device_queue_pair = Some(window.device_queue_pair().clone());
+                }
+            }
+
+            // Other windows' events arrive through the app's event function, which knows which window sent them.
+            let configs = std::mem::take(&mut get_tin_mut().extra_windows);
+            let mut windows = Vec::with_capacity(configs.len());
+            for config in configs.iter() {
+                let nannou_id = window_builder(app, &config.view).build().unwrap();
+                if let Some(window) = app.window(nannou_id) {
+                    window.set_cursor_visible(config.view.is_cursor_visible());
+                    move_window(&window, config.view.get_position());
+                    let (width, height) = window.inner_size_points();
+                    get_tin_mut()
+                        .window_sizes
+                        .insert(config.id, (width as Double, height as Double));
+                }
+                windows.push((nannou_id, config));
+            }
+
+            let scene = S::setup();
+            let windows = windows
+                .into_iter()
+                .map(|(nannou_id, config)| NannouWindow {
+                    nannou_id,
+                    id: config.id,
+                    scene: match config.scene {
+                        TinWindowScene::Shared => None,
+                        TinWindowScene::Own(setup) => Some(setup()),
+                    },
+                })
+                .collect();
+            NannouModel {
+                scene,
+                main_window,
+                windows,
+            }
+        }
+
+        /// Events from windows besides the main one, sent to the scene they show along with the window.
+        fn event<S>(app: &App, model: &mut NannouModel<S>, event: Event)
+        where
+            S: TScene + 'static,
+        {
+            let (nannou_id, simple, raw) = match event {
+                Event::WindowEvent { id, simple, raw } if id != model.main_window => (id, simple, raw),
+                _ => return,
+            };
+            let index = match model.windows.iter().position(|window| window.nannou_id == nannou_id) {
+                Some(index) => index,
+                None => return,
+            };
+            let tin_event = match &raw {
+                RawWindowEvent::KeyboardInput { input, .. } => Some(tin_key_event_from(app, input)),
+                _ => simple.and_then(|simple| tin_event_from(app, simple)),
+            };
+            let tin_event = match tin_event {
+                Some(tin_event) => tin_event,
+                None => return,
+            };
+            let should_close = matches!(tin_event, TinEvent::WindowCloseRequested);
+            let NannouModel { scene, windows, .. } = model;
+            let window = &mut windows[index];
+            match &mut window.scene {
+                Some(own_scene) => dispatch_window_event(own_scene.as_mut(), window.id, tin_event),
+                None => dispatch_window_event(scene, window.id, tin_event),
+            }
+            // Nannou closes the window itself. The app keeps running until the main window closes.
+            if should_close {
+                let mut window = windows.remove(index);
+                if let Some(own_scene) = &mut window.scene {
+                    own_scene.teardown();
+                }
+                get_tin_mut().window_sizes.remove(&window.id);
+            }
         }
 
-        // Draw the state of your `Model` into the given `Frame` here.
-        fn view<S>(app: &App, _scene: &S, frame: Frame)
+        /// Nannou draws each window in turn, so only the calls queued for the frame's window are drawn into it.
+        fn view<S>(app: &App, model: &NannouModel<S>, frame: Frame)
         where
             S: TScene,
         {
-            // rendering code goes here
+            let window = if frame.window_id() == model.main_window {
+                TinWindowId::MAIN
+            } else {
+                match model.windows.iter().find(|window| window.nannou_id == frame.window_id()) {
+                    Some(window) => window.id,
+                    None => return,
+                }
+            };
             let bg_color = {
-                let bg_render_color = get_tin().get_background_color();
+                let mut tin = get_tin_mut();
+                tin.use_window(window);
+                tin.process_draw_calls();
+                let bg_render_color = tin.get_background_color();
+                tin.use_window(TinWindowId::MAIN);
                 nannou::color::lin_srgba(
                     bg_render_color.get_red() as Float,
                     bg_render_color.get_green() as Float,
@@ -170,177 +324,378 @@ impl TBackend for NannouBackend {
                 )
             };
             frame.clear(bg_color);
-            // Performance debugging display should be rendered here
-            //get_tin_mut().frame_count = app.elapsed_frames();
-            app.draw().to_frame(app, &frame).unwrap();
+            DRAW.with(|draw| {
+                draw.to_frame(app, &frame).unwrap();
+                draw.reset();
+            });
         }
 
         // Application logic here
 
-        let tin_view = tin_app.view;
-        let (frame_width, frame_height) = {
-            let f = tin_view.get_frame();
-            (f.get_width(), f.get_height())
+        // Updates run at the target frame rate, or with the display's refresh rate without one.
+        let loop_mode = match tin_app.get_fps() {
+            0 => LoopMode::RefreshSync,
+            fps => LoopMode::rate_fps(fps as f64),
         };
 
         nannou::app(model::<S>)
+            .loop_mode(loop_mode)
+            .event(event::<S>)
             .view(view::<S>) // The function that will be called for presenting graphics to a frame.
-            .update(move |_a, scene, update| {
-                let _update_time = update.since_last;
-                get_tin_mut().prepare_for_update();
-                scene.update();
+            .update(move |app, model, update| {
+                let requests = std::mem::take(&mut get_tin_mut().window_requests);
+                for (window, request) in requests {
+                    let nannou_id = if window.is_main() {
+                        Some(model.main_window)
+                    } else {
+                        model.windows.iter().find(|w| w.id == window).map(|w| w.nannou_id)
+                    };
+                    if let Some(window) = nannou_id.and_then(|id| app.window(id)) {
+                        apply_window_request(&window, request);
+                    }
+                }
+                update_scene(&mut model.scene, update.since_last);
+                let NannouModel { scene, windows, .. } = model;
+                for window in windows.iter_mut() {
+                    match &mut window.scene {
+                        Some(own_scene) => update_window_scene(own_scene.as_mut(), window.id),
+                        None => draw_shared_window(scene, window.id),
+                    }
+                }
+                get_tin_mut().use_window(TinWindowId::MAIN);
                 get_tin_mut().did_finish_update();
+                if get_tin().quit_requested {
+                    app.quit();
+                }
+            })
+            .exit(|_app: &App, mut model: NannouModel<S>| {
+                for window in model.windows.iter_mut() {
+                    if let Some(own_scene) = &mut window.scene {
+                        own_scene.teardown();
+                    }
+                }
+                model.scene.teardown();
             })
-            .size(frame_width, frame_height)
             .run();
 
         Ok(())
     }
 }
 
+/// The app's scene, with the windows besides the main one.
+struct NannouModel<S> {
+    scene: S,
+    main_window: nannou::window::Id,
+    windows: Vec<NannouWindow>,
+}
+
+struct NannouWindow {
+    nannou_id: nannou::window::Id,
+    id: TinWindowId,
+    /// The window's own scene, or `None` when it shows the app's scene.
+    scene: Option<Box<dyn TScene>>,
+}
+
+/// A window set up as the view asks, before its event functions are added.
+fn window_builder<'a>(app: &'a App, view: &TinView) -> nannou::window::Builder<'a> {
+    let frame = view.get_frame();
+    let builder = app
+        .new_window()
+        .size(frame.get_width(), frame.get_height())
+        .title(view.get_title())
+        .resizable(view.is_resizable())
+        .decorations(view.has_decorations())
+        .always_on_top(view.is_always_on_top());
+    if view.is_fullscreen() {
+        builder.fullscreen()
+    } else {
+        builder
+    }
+}
+
+fn move_window(window: &nannou::window::Window, position: TinWindowPosition) {
+    let monitor = match window.current_monitor() {
+        Some(monitor) => monitor,
+        None => return,
+    };
+    let (monitor_position, monitor_size) = (monitor.position(), monitor.size());
+    let target = position.resolve(
+        (monitor_position.x, monitor_position.y),
+        (monitor_size.width, monitor_size.height),
+        window.outer_size_pixels(),
+    );
+    if let Some((x, y)) = target {
+        window.set_outer_position_pixels(x, y);
+    }
+}
+
+fn apply_window_request(window: &nannou::window::Window, request: TinWindowRequest) {
+    match request {
+        TinWindowRequest::Title(title) => window.set_title(&title),
+        TinWindowRequest::Size(width, height) => {
+            window.set_inner_size_points(width as Float, height as Float)
+        }
+        TinWindowRequest::Position(position) => move_window(window, position),
+        TinWindowRequest::Fullscreen(fullscreen) => window.set_fullscreen(fullscreen),
+        TinWindowRequest::CursorVisible(visible) => window.set_cursor_visible(visible),
+        TinWindowRequest::AlwaysOnTop(always_on_top) => window.set_always_on_top(always_on_top),
+    }
+}
+
 use crate::key::TinKey;
-use nannou::event::Key;
+use nannou::event::{Key, MouseScrollDelta};
+use nannou::winit::event::{ElementState, KeyboardInput, WindowEvent as RawWindowEvent};
 impl From<Key> for TinKey {
     fn from(k: Key) -> Self {
         match k {
-            Key::Key1 => return TinKey::One,
-            Key::Key2 => return TinKey::Two,
-            Key::Key3 => return TinKey::Three,
-            Key::Key4 => return TinKey::Four,
-            Key::Key5 => return TinKey::Five,
-            Key::Key6 => return TinKey::Six,
-            Key::Key7 => return TinKey::Seven,
-            Key::Key8 => return TinKey::Eight,
-            Key::Key9 => return TinKey::Nine,
-            Key::Key0 => return TinKey::Zero,
-            Key::A => return TinKey::A,
-            Key::B => return TinKey::B,
-            Key::C => return TinKey::C,
-            Key::D => return TinKey::D,
-            Key::E => return TinKey::E,
-            Key::F => return TinKey::F,
-            Key::G => return TinKey::G,
-            Key::H => return TinKey::H,
-            Key::I => return TinKey::I,
-            Key::J => return TinKey::J,
-            Key::K => return TinKey::K,
-            Key::L => return TinKey::L,
-            Key::M => return TinKey::M,
-            Key::N => return TinKey::N,
-            Key::O => return TinKey::O,
-            Key::P => return TinKey::P,
-            Key::Q => return TinKey::Q,
-            Key::R => return TinKey::R,
-            Key::S => return TinKey::S,
-            Key::T => return TinKey::T,
-            Key::U => return TinKey::U,
-            Key::V => return TinKey::V,
-            Key::W => return TinKey::W,
-            Key::X => return TinKey::X,
-            Key::Y => return TinKey::Y,
-            Key::Z => return TinKey::Z,
-            Key::Escape => return TinKey::Escape,
-            Key::F1 => return TinKey::F1,
-            Key::F2 => return TinKey::F2,
-            Key::F3 => return TinKey::F3,
-            Key::F4 => return TinKey::F4,
-            Key::F5 => return TinKey::F5,
-            Key::F6 => return TinKey::F6,
-            Key::F7 => return TinKey::F7,
-            Key::F8 => return TinKey::F8,
-            Key::F9 => return TinKey::F9,
-            Key::F10 => return TinKey::F10,
-            Key::F11 => return TinKey::F11,
-            Key::F12 => return TinKey::F12,
-            Key::F13 => return TinKey::F13,
-            Key::F14 => return TinKey::F14,
-            Key::F15 => return TinKey::F15,
-            Key::F16 => return TinKey::F16,
-            Key::F17 => return TinKey::F17,
-            Key::F18 => return TinKey::F18,
-            Key::F19 => return TinKey::F19,
-            Key::F20 => return TinKey::F20,
-            Key::F21 => return TinKey::F21,
-            Key::F22 => return TinKey::F22,
-            Key::F23 => return TinKey::F23,
-            Key::F24 => return TinKey::F24,
-            Key::Snapshot => return TinKey::Snapshot,
-            Key::Scroll => return TinKey::Scroll,
-            Key::Pause => return TinKey::Pause,
-            Key::Insert => return TinKey::Insert,
-            Key::Home => return TinKey::Home,
-            Key::Delete => return TinKey::Delete,
-            Key::End => return TinKey::End,
-            Key::PageDown => return TinKey::PageDown,
-            Key::PageUp => return TinKey::PageUp,
-            Key::Left => return TinKey::Left,
-            Key::Up => return TinKey::Up,
-            Key::Right => return TinKey::Right,
-            Key::Down => return TinKey::Down,
-            Key::Back => return TinKey::Backspace,
-            Key::Return => return TinKey::Enter,
-            Key::Space => return TinKey::Space,
-            Key::Numlock => return TinKey::NumLock,
-            Key::Numpad0 => return TinKey::Zero,
-            Key::Numpad1 => return TinKey::One,
-            Key::Numpad2 => return TinKey::Two,
-            Key::Numpad3 => return TinKey::Three,
-            Key::Numpad4 => return TinKey::Four,
-            Key::Numpad5 => return TinKey::Five,
-            Key::Numpad6 => return TinKey::Six,
-            Key::Numpad7 => return TinKey::Seven,
-            Key::Numpad8 => return TinKey::Eight,
-            Key::Numpad9 => return TinKey::Nine,
-            Key::Apostrophe => return TinKey::Apostrophe,
-            Key::Backslash => return TinKey::Backslash,
-            Key::Colon => return TinKey::Colon,
-            Key::Comma => return TinKey::Comma,
-            Key::Equals => return TinKey::Equals,
-            Key::LAlt => return TinKey::LAlt,
-            Key::LBracket => return TinKey::LBracket,
-            Key::LControl => return TinKey::LControl,
-            Key::LShift => return TinKey::LShift,
-            Key::Minus => return TinKey::Minus,
-            Key::Period => return TinKey::Period,
-            Key::RAlt => return TinKey::RAlt,
-            Key::RBracket => return TinKey::RBracket,
-            Key::RControl => return TinKey::RControl,
-            Key::RShift => return TinKey::RShift,
-            Key::Semicolon => return TinKey::Semicolon,
-            Key::Slash => return TinKey::Slash,
-            Key::Tab => return TinKey::Tab,
-            Key::Grave => return TinKey::GraveAccent,
-            Key::NumpadAdd => return TinKey::NumpadAdd,
-            Key::NumpadDivide => return TinKey::NumpadDivide,
-            Key::NumpadDecimal => return TinKey::NumpadDecimal,
-            Key::NumpadEnter => return TinKey::NumpadEnter,
-            Key::NumpadEquals => return TinKey::NumpadEquals,
-            Key::NumpadMultiply => return TinKey::NumpadMultiply,
-            Key::NumpadSubtract => return TinKey::NumpadSubtract,
-            _ => TinKey::Unknown,
+            Key::Key1 => TinKey::One,
+            Key::Key2 => TinKey::Two,
+            Key::Key3 => TinKey::Three,
+            Key::Key4 => TinKey::Four,
+            Key::Key5 => TinKey::Five,
+            Key::Key6 => TinKey::Six,
+            Key::Key7 => TinKey::Seven,
+            Key::Key8 => TinKey::Eight,
+            Key::Key9 => TinKey::Nine,
+            Key::Key0 => TinKey::Zero,
+            Key::A => TinKey::A,
+            Key::B => TinKey::B,
+            Key::C => TinKey::C,
+            Key::D => TinKey::D,
+            Key::E => TinKey::E,
+            Key::F => TinKey::F,
+            Key::G => TinKey::G,
+            Key::H => TinKey::H,
+            Key::I => TinKey::I,
+            Key::J => TinKey::J,
+            Key::K => TinKey::K,
+            Key::L => TinKey::L,
+            Key::M => TinKey::M,
+            Key::N => TinKey::N,
+            Key::O => TinKey::O,
+            Key::P => TinKey::P,
+            Key::Q => TinKey::Q,
+            Key::R => TinKey::R,
+            Key::S => TinKey::S,
+            Key::T => TinKey::T,
+            Key::U => TinKey::U,
+            Key::V => TinKey::V,
+            Key::W => TinKey::W,
+            Key::X => TinKey::X,
+            Key::Y => TinKey::Y,
+            Key::Z => TinKey::Z,
+            Key::Escape => TinKey::Escape,
+            Key::F1 => TinKey::F1,
+            Key::F2 => TinKey::F2,
+            Key::F3 => TinKey::F3,
+            Key::F4 => TinKey::F4,
+            Key::F5 => TinKey::F5,
+            Key::F6 => TinKey::F6,
+            Key::F7 => TinKey::F7,
+            Key::F8 => TinKey::F8,
+            Key::F9 => TinKey::F9,
+            Key::F10 => TinKey::F10,
+            Key::F11 => TinKey::F11,
+            Key::F12 => TinKey::F12,
+            Key::F13 => TinKey::F13,
+            Key::F14 => TinKey::F14,
+            Key::F15 => TinKey::F15,
+            Key::F16 => TinKey::F16,
+            Key::F17 => TinKey::F17,
+            Key::F18 => TinKey::F18,
+            Key::F19 => TinKey::F19,
+            Key::F20 => TinKey::F20,
+            Key::F21 => TinKey::F21,
+            Key::F22 => TinKey::F22,
+            Key::F23 => TinKey::F23,
+            Key::F24 => TinKey::F24,
+            Key::Snapshot => TinKey::Snapshot,
+            Key::Scroll => TinKey::Scroll,
+            Key::Pause => TinKey::Pause,
+            Key::Insert => TinKey::Insert,
+            Key::Home => TinKey::Home,
+            Key::Delete => TinKey::Delete,
+            Key::End => TinKey::End,
+            Key::PageDown => TinKey::PageDown,
+            Key::PageUp => TinKey::PageUp,
+            Key::Left => TinKey::Left,
+            Key::Up => TinKey::Up,
+            Key::Right => TinKey::Right,
+            Key::Down => TinKey::Down,
+            Key::Back => TinKey::Backspace,
+            Key::Return => TinKey::Enter,
+            Key::Space => TinKey::Space,
+            Key::Compose => TinKey::Compose,
+            Key::Caret => TinKey::Caret,
+            Key::Numlock => TinKey::NumLock,
+            Key::Numpad0 => TinKey::Numpad0,
+            Key::Numpad1 => TinKey::Numpad1,
+            Key::Numpad2 => TinKey::Numpad2,
+            Key::Numpad3 => TinKey::Numpad3,
+            Key::Numpad4 => TinKey::Numpad4,
+            Key::Numpad5 => TinKey::Numpad5,
+            Key::Numpad6 => TinKey::Numpad6,
+            Key::Numpad7 => TinKey::Numpad7,
+            Key::Numpad8 => TinKey::Numpad8,
+            Key::Numpad9 => TinKey::Numpad9,
+            Key::NumpadAdd => TinKey::NumpadAdd,
+            Key::NumpadDivide => TinKey::NumpadDivide,
+            Key::NumpadDecimal => TinKey::NumpadDecimal,
+            Key::NumpadComma => TinKey::NumpadComma,
+            Key::NumpadEnter => TinKey::NumpadEnter,
+            Key::NumpadEquals => TinKey::NumpadEquals,
+            Key::NumpadMultiply => TinKey::NumpadMultiply,
+            Key::NumpadSubtract => TinKey::NumpadSubtract,
+            Key::AbntC1 => TinKey::AbntC1,
+            Key::AbntC2 => TinKey::AbntC2,
+            Key::Apostrophe => TinKey::Apostrophe,
+            Key::Apps => TinKey::Menu,
+            Key::Asterisk => TinKey::Asterisk,
+            Key::At => TinKey::At,
+            Key::Ax => TinKey::Ax,
+            Key::Backslash => TinKey::Backslash,
+            Key::Calculator => TinKey::Calculator,
+            Key::Capital => TinKey::CapsLock,
+            Key::Colon => TinKey::Colon,
+            Key::Comma => TinKey::Comma,
+            Key::Convert => TinKey::Convert,
+            Key::Equals => TinKey::Equals,
+            Key::Grave => TinKey::GraveAccent,
+            Key::Kana => TinKey::Kana,
+            Key::Kanji => TinKey::Kanji,
+            Key::LAlt => TinKey::LAlt,
+            Key::LBracket => TinKey::LBracket,
+            Key::LControl => TinKey::LControl,
+            Key::LShift => TinKey::LShift,
+            Key::LWin => TinKey::LSuper,
+            Key::Mail => TinKey::Mail,
+            Key::MediaSelect => TinKey::MediaSelect,
+            Key::MediaStop => TinKey::MediaStop,
+            Key::Minus => TinKey::Minus,
+            Key::Mute => TinKey::Mute,
+            Key::MyComputer => TinKey::MyComputer,
+            Key::NavigateForward => TinKey::NavigateForward,
+            Key::NavigateBackward => TinKey::NavigateBackward,
+            Key::NextTrack => TinKey::NextTrack,
+            Key::NoConvert => TinKey::NoConvert,
+            Key::OEM102 => TinKey::Oem102,
+            Key::Period => TinKey::Period,
+            Key::PlayPause => TinKey::PlayPause,
+            Key::Plus => TinKey::Plus,
+            Key::Power => TinKey::Power,
+            Key::PrevTrack => TinKey::PrevTrack,
+            Key::RAlt => TinKey::RAlt,
+            Key::RBracket => TinKey::RBracket,
+            Key::RControl => TinKey::RControl,
+            Key::RShift => TinKey::RShift,
+            Key::RWin => TinKey::RSuper,
+            Key::Semicolon => TinKey::Semicolon,
+            Key::Slash => TinKey::Slash,
+            Key::Sleep => TinKey::Sleep,
+            Key::Stop => TinKey::Stop,
+            Key::Sysrq => TinKey::Sysrq,
+            Key::Tab => TinKey::Tab,
+            Key::Underline => TinKey::Underline,
+            Key::Unlabeled => TinKey::Unknown,
+            Key::VolumeDown => TinKey::VolumeDown,
+            Key::VolumeUp => TinKey::VolumeUp,
+            Key::Wake => TinKey::Wake,
+            Key::WebBack => TinKey::WebBack,
+            Key::WebFavorites => TinKey::WebFavorites,
+            Key::WebForward => TinKey::WebForward,
+            Key::WebHome => TinKey::WebHome,
+            Key::WebRefresh => TinKey::WebRefresh,
+            Key::WebSearch => TinKey::WebSearch,
+            Key::WebStop => TinKey::WebStop,
+            Key::Yen => TinKey::Yen,
+            Key::Copy => TinKey::Copy,
+            Key::Paste => TinKey::Paste,
+            Key::Cut => TinKey::Cut,
         }
     }
 }
 
-impl From<WindowEvent> for TinEvent {
-    fn from(e: WindowEvent) -> Self {
-        match e {
-            Moved(p) => Self::MouseMoved(TinPoint::from(p)),
-            KeyPressed(k) => Self::KeyDown(TinKey::from(k)),
-            KeyReleased(k) => Self::KeyUp(TinKey::from(k)),
-            MouseMoved(v) => TinEvent::MouseMoved(TinPoint::from(v)),
-            MousePressed(b) => match b {
-                MouseButton::Left => TinEvent::MouseDown,
-                MouseButton::Right => TinEvent::RightMouseDown,
-                _ => TinEvent::OtherMouseDown,
-            },
-            MouseReleased(b) => match b {
-                MouseButton::Left => TinEvent::MouseUp,
-                MouseButton::Right => TinEvent::RightMouseUp,
-                _ => TinEvent::OtherMouseUp,
-            },
-            _ => TinEvent::Unknown,
+impl From<nannou::winit::event::ModifiersState> for TinModifiers {
+    fn from(state: nannou::winit::event::ModifiersState) -> Self {
+        let mut modifiers = TinModifiers::NONE;
+        if state.shift() {
+            modifiers.insert(TinModifiers::SHIFT);
+        }
+        if state.ctrl() {
+            modifiers.insert(TinModifiers::CONTROL);
         }
+        if state.alt() {
+            modifiers.insert(TinModifiers::ALT);
+        }
+        if state.logo() {
+            modifiers.insert(TinModifiers::LOGO);
+        }
+        modifiers
+    }
+}
+
+impl From<MouseButton> for TinMouseButton {
+    fn from(button: MouseButton) -> Self {
+        match button {
+            MouseButton::Left => TinMouseButton::Left,
+            MouseButton::Right => TinMouseButton::Right,
+            MouseButton::Middle => TinMouseButton::Middle,
+            MouseButton::Other(n) => TinMouseButton::Other(n),
+        }
+    }
+}
+
+/**
+ Converts a window event, using the app for the mouse position and modifier keys that the event doesn't carry.
+ Key events are skipped, since they are sent from the raw event that also has the scan code.
+*/
+fn tin_event_from(app: &App, e: WindowEvent) -> Option<TinEvent> {
+    let mouse_event = |button: MouseButton| TinMouseEvent {
+        button: TinMouseButton::from(button),
+        position: TinPoint::from(app.mouse.position()),
+        modifiers: TinModifiers::from(app.keys.mods),
+    };
+    let tin_event = match e {
+        KeyPressed(_) | KeyReleased(_) => return None,
+        ReceivedCharacter(c) => TinEvent::CharacterInput(c),
+        MouseMoved(v) => TinEvent::MouseMoved(TinPoint::from(v)),
+        MousePressed(b) => TinEvent::MouseDown(mouse_event(b)),
+        MouseReleased(b) => TinEvent::MouseUp(mouse_event(b)),
+        MouseWheel(MouseScrollDelta::LineDelta(x, y), _) => {
+            TinEvent::MouseWheel(TinScrollDelta::Lines(x as Double, y as Double))
+        }
+        MouseWheel(MouseScrollDelta::PixelDelta(p), _) => {
+            TinEvent::MouseWheel(TinScrollDelta::Pixels(p.x, p.y))
+        }
+        MouseEntered => TinEvent::MouseEntered,
+        MouseExited => TinEvent::MouseExited,
+        Moved(p) => TinEvent::WindowMoved(p.x as Int, p.y as Int),
+        Resized(size) => TinEvent::WindowResized(size.x as UInt, size.y as UInt),
+        Focused => TinEvent::WindowFocused,
+        Unfocused => TinEvent::WindowUnfocused,
+        Closed => TinEvent::WindowCloseRequested,
+        HoveredFile(path) => TinEvent::FileHovered(path),
+        HoveredFileCancelled => TinEvent::FileHoverCancelled,
+        DroppedFile(path) => TinEvent::FileDropped(path),
+        _ => TinEvent::Unknown,
+    };
+    Some(tin_event)
+}
+
+/// Converts a raw keyboard event, which unlike nannou's key events keeps the scan code.
+fn tin_key_event_from(app: &App, input: &KeyboardInput) -> TinEvent {
+    let key = input.virtual_keycode.map_or(TinKey::Unknown, TinKey::from);
+    let pressed = input.state == ElementState::Pressed;
+    let key_event = TinKeyEvent {
+        key,
+        scan_code: input.scancode,
+        modifiers: TinModifiers::from(app.keys.mods),
+        is_repeat: pressed && get_tin().input.is_key_down(key),
+    };
+    if pressed {
+        TinEvent::KeyDown(key_event)
+    } else {
+        TinEvent::KeyUp(key_event)
     }
 }
 
@@ -9,9 +9,8 @@ impl EllipseRenderer for NannouBackend {
         brush: crate::brush::TBrush,
         state: crate::context::DrawState,
     ) {
-        eprintln!("NannouBackend::ellipse_in_tinrect()");
-
-        let draw = nannou::prelude::Draw::new()
+        let draw = self
+            .get_draw()
             .scale(state.scale as f32)
             .translate(nannou::prelude::vec3(
                 state.translation.0 as f32,
@@ -22,11 +21,10 @@ impl EllipseRenderer for NannouBackend {
 
         let d = draw
             .ellipse()
-            .x_y(center.get_x() as f32, center.get_x() as f32)
+            .x_y(center.get_x() as f32, center.get_y() as f32)
             .w(w as f32)
             .h(h as f32);
 
         crate::draw_with_brush!(d, brush);
-        todo!("Ellipse render inside TinRect for NannouBackend not implemented");
     }
 }
@@ -1,15 +1,23 @@
-use crate::backends::{ImageRenderer, nannou::NannouBackend};
+use crate::{
+    backends::{nannou::NannouBackend, ImageRenderer},
+    point::TPoint,
+};
 
 impl ImageRenderer for NannouBackend {
     fn image_with_size_and_resize(
         &mut self,
-        image: &crate::image::TinImage,
+        image: &crate::image::TinImageHandle,
         center: crate::point::TinPoint,
         width: crate::Double,
         height: crate::Double,
-        resize: bool,
+        _resize: bool,
         state: crate::context::DrawState,
     ) {
+        // Textures can only be uploaded once a window has given the backend its GPU device.
+        let device_queue_pair = match &self.device_queue_pair {
+            Some(device_queue_pair) => device_queue_pair.clone(),
+            None => return,
+        };
         let draw = self
             .get_draw()
             .scale(state.scale as f32)
@@ -19,6 +27,15 @@ impl ImageRenderer for NannouBackend {
                 0.0,
             ))
             .rotate(state.rotation as f32);
-        todo!()
+        let texture = self.textures.get_or_insert_with(image, |image| {
+            nannou::wgpu::Texture::from_image(device_queue_pair.as_ref(), &image.image)
+        });
+        draw.texture(texture)
+            .x_y(center.get_x() as f32, center.get_y() as f32)
+            .w_h(width as f32, height as f32);
+    }
+
+    fn forget_image(&mut self, image_id: crate::ULong) {
+        self.textures.evict(image_id);
     }
 }
@@ -15,7 +15,8 @@ impl LineRenderer for NannouBackend {
 
         let vector1 = nannou::prelude::Vector2::from(tvector1);
         let vector2 = nannou::prelude::Vector2::from(tvector2);
-        let draw = nannou::prelude::Draw::new()
+        let draw = self
+            .get_draw()
             .scale(state.scale as f32)
             .translate(nannou::prelude::vec3(
                 state.translation.0 as f32,
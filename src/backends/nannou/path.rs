@@ -1,22 +1,77 @@
-use crate::{backends::{PathRenderer, nannou::NannouBackend}, point::TPoint};
+use crate::{
+    backends::{nannou::NannouBackend, PathRenderer},
+    brush::TBrush,
+    context::DrawState,
+    point::TPoint,
+    shapes::TinPathSegment,
+};
+use nannou::lyon::math::point;
 
 impl PathRenderer for NannouBackend {
     // MARK: - Path stuff
 
     fn path_begin(&mut self) {
-        todo!()
+        self.path.clear();
     }
 
     fn path_vertex(&mut self, at_point: &impl TPoint) {
-        todo!();
+        self.path.vertex(at_point.get_x(), at_point.get_y());
     }
 
     fn path_add_curve(&mut self, to: &impl TPoint, control1: &impl TPoint, control2: &impl TPoint) {
-        todo!("path_add_curve method in NannouBackend not supported yet");
-        //cg.addCurve(to: to, control1: control1, control2: control2)
+        self.path.add_curve(to, control1, control2);
     }
 
-    fn path_end(&mut self) {
-        todo!("path_end method in NannouBackend not supported yet");
+    fn path_contour(&mut self) {
+        self.path.begin_contour();
+    }
+
+    fn path_end(&mut self, brush: TBrush, state: DrawState) {
+        let to_lyon = |p: &crate::point::TinPoint| point(p.get_x() as f32, p.get_y() as f32);
+        let mut builder = nannou::geom::path::Builder::new();
+        for contour in self.path.get_contours().iter().filter(|c| !c.is_empty()) {
+            for (index, segment) in contour.iter().enumerate() {
+                match segment {
+                    TinPathSegment::Vertex(p) if index == 0 => {
+                        builder.begin(to_lyon(p));
+                    }
+                    TinPathSegment::Vertex(p) => {
+                        builder.line_to(to_lyon(p));
+                    }
+                    TinPathSegment::Curve {
+                        to,
+                        control1,
+                        control2,
+                    } => {
+                        builder.cubic_bezier_to(to_lyon(control1), to_lyon(control2), to_lyon(to));
+                    }
+                }
+            }
+            builder.end(true);
+        }
+        let lyon_path = builder.build();
+        self.path.clear();
+
+        let draw = self
+            .get_draw()
+            .scale(state.scale as f32)
+            .translate(nannou::prelude::vec3(
+                state.translation.0 as f32,
+                state.translation.1 as f32,
+                0.0,
+            ))
+            .rotate(state.rotation as f32);
+        let (fill, stroke) = match brush {
+            TBrush::Fill(f) => (Some(f), None),
+            TBrush::Stroke(s) => (None, Some(s)),
+            TBrush::FillAndStroke(f, s) => (Some(f), Some(s)),
+            TBrush::Disabled => (None, None),
+        };
+        if let Some(color) = fill {
+            draw.path().fill().color(color).events(lyon_path.iter());
+        }
+        if let Some(color) = stroke {
+            draw.path().stroke().color(color).events(lyon_path.iter());
+        }
     }
 }
@@ -17,7 +17,8 @@ impl RectRenderer for NannouBackend {
         state: crate::context::DrawState,
     ) {
         let center = &with_rect.center;
-        let draw = nannou::prelude::Draw::new()
+        let draw = self
+            .get_draw()
             .scale(state.scale as f32)
             .translate(nannou::prelude::vec3(
                 state.translation.0 as f32,
@@ -1,4 +1,8 @@
-use crate::{backends::{TextRenderer, nannou::NannouBackend}, point::TPoint};
+use crate::{
+    backends::{nannou::NannouBackend, TextRenderer},
+    color::{TColor, TinColor},
+    point::TPoint,
+};
 
 impl TextRenderer for NannouBackend {
     fn text(
@@ -6,8 +10,22 @@ impl TextRenderer for NannouBackend {
         message: &String,
         font: &crate::text::TinFont,
         center: crate::point::TinPoint,
+        color: TinColor,
         state: crate::context::DrawState,
     ) {
+        let nannou_font = self.fonts.entry(font.id).or_insert_with(|| {
+            let loaded =
+                nannou::text::Font::try_from_vec_and_index(font.font.as_slice().to_vec(), font.font_index);
+            if loaded.is_none() {
+                eprintln!("WARNING: The font could not be read by nannou, so its text is not drawn.");
+            }
+            loaded
+        });
+        let nannou_font = match nannou_font {
+            Some(nannou_font) => nannou_font.clone(),
+            None => return,
+        };
+
         let draw = self
             .get_draw()
             .scale(state.scale as f32)
@@ -17,10 +35,28 @@ impl TextRenderer for NannouBackend {
                 0.0,
             ))
             .rotate(state.rotation as f32);
-        let d = draw
-            .text(message)
-            .x_y(center.get_x() as f32, center.get_y() as f32);
-        todo!("text method in NannouBackend not supported yet");
-        // Draw text at location with default size
+
+        // Size the text box to the measured line, then place the box so the anchor follows the font alignment.
+        let metrics = font.metrics();
+        let width = font.text_width(message);
+        let height = metrics.ascent - metrics.descent;
+        let (dx, dy) = font.anchor_offset(width);
+        let ascent = font.ascent() as f64;
+        let box_x = center.get_x() + dx + width as f64 / 2.0;
+        let box_y = center.get_y() + dy + ascent - height as f64 / 2.0;
+        draw.text(message)
+            .font(nannou_font)
+            .font_size(font.get_font_size().round() as u32)
+            .no_line_wrap()
+            .left_justify()
+            .align_text_top()
+            .w_h(width, height)
+            .x_y(box_x as f32, box_y as f32)
+            .rgba(
+                color.get_red() as f32,
+                color.get_green() as f32,
+                color.get_blue() as f32,
+                color.get_alpha() as f32,
+            );
     }
 }
@@ -11,7 +11,8 @@ impl TriangleRenderer for NannouBackend {
         let vector2 = TinVector2::from(triangle.point2);
         let vector3 = TinVector2::from(triangle.point3);
 
-        let draw = nannou::prelude::Draw::new()
+        let draw = self
+            .get_draw()
             .scale(state.scale as f32)
             .translate(nannou::prelude::vec3(
                 state.translation.0 as f32,
@@ -0,0 +1,351 @@
+pub(crate) mod arc;
+pub(crate) mod ellipse;
+pub(crate) mod image;
+pub(crate) mod line;
+pub(crate) mod path;
+pub(crate) mod rect;
+pub(crate) mod state;
+#[cfg(feature = "text")]
+pub(crate) mod text;
+pub(crate) mod triangle;
+
+use ::image::{Rgba, RgbaImage};
+use std::time::Instant;
+
+use crate::{
+    backends::{TBackend, TinRenderer},
+    brush::TBrush,
+    color::{TColor, TinColor},
+    context::{dispatch_event, get_tin, get_tin_mut, update_scene, DrawState},
+    event::TinEvent,
+    frame::TinFrame,
+    image::{TinBlendMode, TinImage},
+    point::TPoint,
+    scene::TScene,
+    shapes::{even_odd_spans, TinPath, TinRect},
+    vector2::TinVector2,
+    view::TinWindowRequest,
+    Double, Tin,
+};
+
+/// Stroke width used for shapes that are not given one, in pixels.
+const DEFAULT_STROKE_WIDTH: Double = 1.0;
+
+/**
+ A CPU renderer that rasterizes draw calls into an RGBA image.
+ It needs no window or GPU, so it backs offscreen rendering and headless runs.
+ Coordinates are pixels with the origin at the center of the canvas and y pointing up.
+*/
+pub(crate) struct SoftwareBackend {
+    canvas: RgbaImage,
+    path: TinPath,
+    /// Pixels outside these columns and rows, from the top left and ending before the last values, are left alone.
+    clip: Option<(i64, i64, i64, i64)>,
+
+    #[cfg(feature = "text")]
+    glyphs: crate::text::TinGlyphCache,
+}
+
+impl SoftwareBackend {
+    pub(crate) fn get_canvas(&self) -> &RgbaImage {
+        &self.canvas
+    }
+
+    /// Copy the current canvas into a new image.
+    pub(crate) fn to_image(&self) -> TinImage {
+        TinImage::from_image(::image::DynamicImage::ImageRgba8(self.canvas.clone()))
+    }
+
+    /// Convert a point in drawing coordinates to continuous pixel coordinates.
+    pub(crate) fn to_pixel(&self, x: Double, y: Double) -> (Double, Double) {
+        (
+            x + self.canvas.width() as Double / 2.0,
+            self.canvas.height() as Double / 2.0 - y,
+        )
+    }
+
+    /// Convert continuous pixel coordinates back to drawing coordinates.
+    pub(crate) fn from_pixel(&self, px: Double, py: Double) -> (Double, Double) {
+        (
+            px - self.canvas.width() as Double / 2.0,
+            self.canvas.height() as Double / 2.0 - py,
+        )
+    }
+
+    /// Fill and/or stroke a shape given in drawing coordinates, according to the brush.
+    pub(crate) fn draw_shape(
+        &mut self,
+        points: &[TinVector2],
+        closed: bool,
+        brush: TBrush,
+        state: DrawState,
+    ) {
+        let pixels: Vec<(Double, Double)> = points
+            .iter()
+            .map(|p| {
+                let (x, y) = state.apply(p.x, p.y);
+                self.to_pixel(x, y)
+            })
+            .collect();
+        let stroke_width = DEFAULT_STROKE_WIDTH * state.scale.abs();
+        match brush {
+            TBrush::Fill(c) => self.fill_polygon(&pixels, c),
+            TBrush::Stroke(c) => self.stroke_polyline(&pixels, closed, stroke_width, c),
+            TBrush::FillAndStroke(f, s) => {
+                self.fill_polygon(&pixels, f);
+                self.stroke_polyline(&pixels, closed, stroke_width, s);
+            }
+            TBrush::Disabled => {}
+        }
+    }
+
+    /**
+     Draw a rectangular grid of samples, such as an image, with the state's transform.
+     The rectangle is given in drawing coordinates by its top left corner and size. Each canvas pixel
+     it covers is mapped back onto it, and `sample` is asked for the color at that point,
+     as fractions (u, v) of the width and height measured from the top left.
+    */
+    pub(crate) fn draw_sampled(
+        &mut self,
+        left: Double,
+        top: Double,
+        width: Double,
+        height: Double,
+        state: DrawState,
+        sample: impl Fn(Double, Double) -> Option<TinColor>,
+    ) {
+        if width <= 0.0 || height <= 0.0 || state.scale == 0.0 {
+            return;
+        }
+        let corners = [
+            (left, top),
+            (left + width, top),
+            (left + width, top - height),
+            (left, top - height),
+        ]
+        .map(|(x, y)| {
+            let (tx, ty) = state.apply(x, y);
+            self.to_pixel(tx, ty)
+        });
+        let min_x = corners.iter().map(|c| c.0).fold(Double::INFINITY, Double::min);
+        let max_x = corners.iter().map(|c| c.0).fold(Double::NEG_INFINITY, Double::max);
+        let min_y = corners.iter().map(|c| c.1).fold(Double::INFINITY, Double::min);
+        let max_y = corners.iter().map(|c| c.1).fold(Double::NEG_INFINITY, Double::max);
+        let (sin, cos) = state.rotation.sin_cos();
+
+        let (canvas_width, canvas_height) = (
+            self.canvas.width() as Double,
+            self.canvas.height() as Double,
+        );
+        let rows = min_y.floor().max(0.0) as i64..max_y.ceil().min(canvas_height) as i64;
+        let columns = min_x.floor().max(0.0) as i64..max_x.ceil().min(canvas_width) as i64;
+        for row in rows {
+            for column in columns.clone() {
+                // Undo scale, then translation, then rotation.
+                let (x, y) = self.from_pixel(column as Double + 0.5, row as Double + 0.5);
+                let (x, y) = (
+                    x / state.scale - state.translation.0,
+                    y / state.scale - state.translation.1,
+                );
+                let (x, y) = (x * cos + y * sin, -x * sin + y * cos);
+
+                let u = (x - left) / width;
+                let v = (top - y) / height;
+                if !(0.0..1.0).contains(&u) || !(0.0..1.0).contains(&v) {
+                    continue;
+                }
+                if let Some(color) = sample(u, v) {
+                    self.blend_pixel(column, row, color);
+                }
+            }
+        }
+    }
+
+    /// Blend a color onto one pixel, ignoring pixels off the canvas.
+    pub(crate) fn blend_pixel(&mut self, x: i64, y: i64, color: TinColor) {
+        if x < 0 || y < 0 || x >= self.canvas.width() as i64 || y >= self.canvas.height() as i64 {
+            return;
+        }
+        if let Some((left, top, right, bottom)) = self.clip {
+            if x < left || y < top || x >= right || y >= bottom {
+                return;
+            }
+        }
+        let pixel = self.canvas.get_pixel_mut(x as u32, y as u32);
+        let backdrop = [
+            pixel.0[0] as Double / 255.0,
+            pixel.0[1] as Double / 255.0,
+            pixel.0[2] as Double / 255.0,
+            pixel.0[3] as Double / 255.0,
+        ];
+        let source = [
+            color.get_red(),
+            color.get_green(),
+            color.get_blue(),
+            color.get_alpha(),
+        ];
+        let result = TinBlendMode::Normal.composite(backdrop, source, 1.0);
+        *pixel = Rgba([
+            (result[0] * 255.0).round() as u8,
+            (result[1] * 255.0).round() as u8,
+            (result[2] * 255.0).round() as u8,
+            (result[3] * 255.0).round() as u8,
+        ]);
+    }
+
+    /// Scanline fill with the even-odd rule, sampling pixel centers.
+    pub(crate) fn fill_polygon(&mut self, points: &[(Double, Double)], color: TinColor) {
+        self.fill_contours(&[points.to_vec()], color);
+    }
+
+    /// Fill several closed contours together, so overlapping areas are cut out as holes.
+    pub(crate) fn fill_contours(&mut self, contours: &[Vec<(Double, Double)>], color: TinColor) {
+        let contours: Vec<Vec<(Double, Double)>> =
+            contours.iter().filter(|c| c.len() >= 3).cloned().collect();
+        let points = contours.iter().flatten();
+        let min_y = points.clone().map(|p| p.1).fold(Double::INFINITY, Double::min);
+        let max_y = points.map(|p| p.1).fold(Double::NEG_INFINITY, Double::max);
+        if contours.is_empty() {
+            return;
+        }
+        let first_row = min_y.floor().max(0.0) as i64;
+        let last_row = max_y.ceil().min(self.canvas.height() as Double) as i64;
+
+        for row in first_row..last_row {
+            for (start, end) in even_odd_spans(&contours, row as Double + 0.5) {
+                let start = (start - 0.5).ceil().max(0.0) as i64;
+                let end = (end - 0.5).ceil().min(self.canvas.width() as Double) as i64;
+                for column in start..end {
+                    self.blend_pixel(column, row, color);
+                }
+            }
+        }
+    }
+
+    /// Stroke connected segments as quads of the given width, in pixels.
+    pub(crate) fn stroke_polyline(
+        &mut self,
+        points: &[(Double, Double)],
+        closed: bool,
+        width: Double,
+        color: TinColor,
+    ) {
+        if points.len() < 2 {
+            return;
+        }
+        let segment_count = if closed { points.len() } else { points.len() - 1 };
+        for i in 0..segment_count {
+            let a = points[i];
+            let b = points[(i + 1) % points.len()];
+            self.stroke_segment(a, b, width, color);
+        }
+    }
+
+    pub(crate) fn stroke_segment(
+        &mut self,
+        a: (Double, Double),
+        b: (Double, Double),
+        width: Double,
+        color: TinColor,
+    ) {
+        let direction = TinVector2::from_xy(b.0 - a.0, b.1 - a.1);
+        if direction.get_magnitude() == 0.0 {
+            return;
+        }
+        // Keep hairlines visible by never going below one pixel.
+        let mut normal = direction.perpendicular_clockwise();
+        normal.set_magnitude(width.max(1.0) / 2.0);
+        let quad = [
+            (a.0 + normal.x, a.1 + normal.y),
+            (b.0 + normal.x, b.1 + normal.y),
+            (b.0 - normal.x, b.1 - normal.y),
+            (a.0 - normal.x, a.1 - normal.y),
+        ];
+        self.fill_polygon(&quad, color);
+    }
+}
+
+impl TinRenderer for SoftwareBackend {
+    fn prepare(&mut self, frame: TinFrame) {
+        self.canvas = RgbaImage::new(frame.get_width(), frame.get_height());
+    }
+
+    fn set_clip(&mut self, clip: Option<TinRect>) {
+        self.clip = clip.map(|rect| {
+            let (half_width, half_height) = (rect.get_width() / 2.0, rect.get_height() / 2.0);
+            let (left, top) = self.to_pixel(rect.center.get_x() - half_width, rect.center.get_y() + half_height);
+            let (right, bottom) = self.to_pixel(rect.center.get_x() + half_width, rect.center.get_y() - half_height);
+            (left.round() as i64, top.round() as i64, right.round() as i64, bottom.round() as i64)
+        });
+    }
+
+    fn background(&mut self, color: TinColor) {
+        let pixel = Rgba([
+            (color.get_red() * 255.0).round() as u8,
+            (color.get_green() * 255.0).round() as u8,
+            (color.get_blue() * 255.0).round() as u8,
+            (color.get_alpha() * 255.0).round() as u8,
+        ]);
+        for p in self.canvas.pixels_mut() {
+            *p = pixel;
+        }
+    }
+
+    fn prepare_for_update(&mut self) {
+        self.path.clear();
+    }
+
+    fn did_finish_update(&mut self) {}
+}
+
+impl TBackend for SoftwareBackend {
+    fn new() -> Self {
+        Self {
+            canvas: RgbaImage::new(1, 1),
+            path: TinPath::new(),
+            clip: None,
+
+            #[cfg(feature = "text")]
+            glyphs: crate::text::TinGlyphCache::new(),
+        }
+    }
+
+    /// Runs the scene without a window, rendering each frame into the canvas at the target frame rate, until `quit`.
+    fn run<S>(app: Tin<S>) -> Result<(), ()>
+    where
+        S: TScene + 'static,
+    {
+        if !std::mem::take(&mut get_tin_mut().extra_windows).is_empty() {
+            eprintln!("WARNING: The software backend draws only the main window.");
+        }
+        let mut scene = S::setup();
+        let mut last_frame_time = Instant::now();
+        let mut last_update_time = Instant::now();
+        loop {
+            // Without a window, only a new size matters: the canvas is resized to it, and the scene told as if a window had been.
+            let requests = std::mem::take(&mut get_tin_mut().window_requests);
+            for (_, request) in requests.into_iter().filter(|(window, _)| window.is_main()) {
+                if let TinWindowRequest::Size(width, height) = request {
+                    get_tin_mut().prepare(TinFrame::new(width, height));
+                    dispatch_event(&mut scene, TinEvent::WindowResized(width, height));
+                }
+            }
+
+            let now = Instant::now();
+            update_scene(&mut scene, now - last_update_time);
+            last_update_time = now;
+            {
+                let mut tin = get_tin_mut();
+                tin.process_draw_calls();
+                tin.did_finish_update();
+            }
+            if get_tin().quit_requested {
+                break;
+            }
+            crate::stopwatch::pace_frames(app.get_fps(), last_frame_time);
+            last_frame_time = Instant::now();
+        }
+        scene.teardown();
+        Ok(())
+    }
+}
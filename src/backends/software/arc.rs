@@ -0,0 +1,38 @@
+use std::f64::consts::TAU;
+
+use crate::{
+    backends::software::{ellipse::ELLIPSE_SEGMENTS, SoftwareBackend},
+    brush::TBrush,
+    context::DrawState,
+    point::TPoint,
+    shapes::{ArcRenderer, TinArc},
+    vector2::TinVector2,
+    Double,
+};
+
+impl ArcRenderer for SoftwareBackend {
+    fn arc(&mut self, arc: TinArc, brush: TBrush, state: DrawState) {
+        let (cx, cy) = (arc.center.get_x(), arc.center.get_y());
+        let sweep = arc.end_angle - arc.start_angle;
+        let segments = ((sweep.abs() / TAU) * ELLIPSE_SEGMENTS as Double).ceil().max(1.0) as usize;
+        let rim: Vec<TinVector2> = (0..=segments)
+            .map(|i| {
+                let theta = arc.start_angle + sweep * i as Double / segments as Double;
+                TinVector2::from_xy(cx + arc.radius * theta.cos(), cy + arc.radius * theta.sin())
+            })
+            .collect();
+
+        // The fill is a pie slice, while the stroke only follows the rim.
+        let mut pie = vec![TinVector2::from_xy(cx, cy)];
+        pie.extend(rim.iter().copied());
+        match brush {
+            TBrush::Fill(c) => self.draw_shape(&pie, true, TBrush::Fill(c), state),
+            TBrush::Stroke(c) => self.draw_shape(&rim, false, TBrush::Stroke(c), state),
+            TBrush::FillAndStroke(f, s) => {
+                self.draw_shape(&pie, true, TBrush::Fill(f), state);
+                self.draw_shape(&rim, false, TBrush::Stroke(s), state);
+            }
+            TBrush::Disabled => {}
+        }
+    }
+}
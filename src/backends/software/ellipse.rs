@@ -0,0 +1,29 @@
+use std::f64::consts::TAU;
+
+use crate::{
+    backends::software::SoftwareBackend,
+    brush::TBrush,
+    context::DrawState,
+    point::{TPoint, TinPoint},
+    shapes::EllipseRenderer,
+    vector2::TinVector2,
+    Double,
+};
+
+/// Number of straight segments used to approximate a full ellipse.
+pub(crate) const ELLIPSE_SEGMENTS: usize = 64;
+
+impl EllipseRenderer for SoftwareBackend {
+    fn ellipse(&mut self, center: TinPoint, w: Double, h: Double, brush: TBrush, state: DrawState) {
+        let points: Vec<TinVector2> = (0..ELLIPSE_SEGMENTS)
+            .map(|i| {
+                let theta = TAU * i as Double / ELLIPSE_SEGMENTS as Double;
+                TinVector2::from_xy(
+                    center.get_x() + w / 2.0 * theta.cos(),
+                    center.get_y() + h / 2.0 * theta.sin(),
+                )
+            })
+            .collect();
+        self.draw_shape(&points, true, brush, state);
+    }
+}
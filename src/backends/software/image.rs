@@ -0,0 +1,39 @@
+use ::image::GenericImageView;
+
+use crate::{
+    backends::{software::SoftwareBackend, ImageRenderer},
+    color::{TColor, TinColor},
+    context::DrawState,
+    image::TinImageHandle,
+    point::{TPoint, TinPoint},
+    Double,
+};
+
+impl ImageRenderer for SoftwareBackend {
+    fn image_with_size_and_resize(
+        &mut self,
+        image: &TinImageHandle,
+        center: TinPoint,
+        width: Double,
+        height: Double,
+        _resize: bool,
+        state: DrawState,
+    ) {
+        let source = &image.get_image().image;
+        let (source_width, source_height) = (source.width(), source.height());
+        let left = center.get_x() - width / 2.0;
+        let top = center.get_y() + height / 2.0;
+        self.draw_sampled(left, top, width, height, state, |u, v| {
+            let p = source.get_pixel(
+                (u * source_width as Double) as u32,
+                (v * source_height as Double) as u32,
+            );
+            Some(TinColor::from_rgba(
+                p.0[0] as Double / 255.0,
+                p.0[1] as Double / 255.0,
+                p.0[2] as Double / 255.0,
+                p.0[3] as Double / 255.0,
+            ))
+        });
+    }
+}
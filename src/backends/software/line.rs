@@ -0,0 +1,30 @@
+use crate::{
+    backends::software::SoftwareBackend,
+    brush::TBrush,
+    context::DrawState,
+    point::{TPoint, TinPoint},
+    shapes::LineRenderer,
+    Double,
+};
+
+impl LineRenderer for SoftwareBackend {
+    fn line(
+        &mut self,
+        point1: TinPoint,
+        point2: TinPoint,
+        width: Double,
+        brush: TBrush,
+        state: DrawState,
+    ) {
+        // A line has no interior, so the fill color is only used when stroking is disabled.
+        let color = match brush {
+            TBrush::Stroke(c) | TBrush::FillAndStroke(_, c) | TBrush::Fill(c) => c,
+            TBrush::Disabled => return,
+        };
+        let (x1, y1) = state.apply(point1.get_x(), point1.get_y());
+        let (x2, y2) = state.apply(point2.get_x(), point2.get_y());
+        let a = self.to_pixel(x1, y1);
+        let b = self.to_pixel(x2, y2);
+        self.stroke_segment(a, b, width * state.scale.abs(), color);
+    }
+}
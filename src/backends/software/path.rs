@@ -0,0 +1,60 @@
+use crate::{
+    backends::{software::SoftwareBackend, PathRenderer},
+    brush::TBrush,
+    context::DrawState,
+    point::TPoint,
+    shapes::PATH_CURVE_STEPS,
+    Double,
+};
+
+impl PathRenderer for SoftwareBackend {
+    fn path_begin(&mut self) {
+        self.path.clear();
+    }
+
+    fn path_vertex(&mut self, at_point: &impl TPoint) {
+        self.path.vertex(at_point.get_x(), at_point.get_y());
+    }
+
+    fn path_add_curve(&mut self, to: &impl TPoint, control1: &impl TPoint, control2: &impl TPoint) {
+        self.path.add_curve(to, control1, control2);
+    }
+
+    fn path_contour(&mut self) {
+        self.path.begin_contour();
+    }
+
+    fn path_end(&mut self, brush: TBrush, state: DrawState) {
+        let contours: Vec<Vec<(Double, Double)>> = self
+            .path
+            .flatten(PATH_CURVE_STEPS)
+            .into_iter()
+            .map(|contour| {
+                contour
+                    .into_iter()
+                    .map(|p| {
+                        let (x, y) = state.apply(p.x, p.y);
+                        self.to_pixel(x, y)
+                    })
+                    .collect()
+            })
+            .collect();
+        self.path.clear();
+
+        let stroke_width = super::DEFAULT_STROKE_WIDTH * state.scale.abs();
+        let (fill, stroke) = match brush {
+            TBrush::Fill(f) => (Some(f), None),
+            TBrush::Stroke(s) => (None, Some(s)),
+            TBrush::FillAndStroke(f, s) => (Some(f), Some(s)),
+            TBrush::Disabled => (None, None),
+        };
+        if let Some(color) = fill {
+            self.fill_contours(&contours, color);
+        }
+        if let Some(color) = stroke {
+            for contour in &contours {
+                self.stroke_polyline(contour, true, stroke_width, color);
+            }
+        }
+    }
+}
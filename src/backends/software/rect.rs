@@ -0,0 +1,54 @@
+use std::f64::consts::FRAC_PI_2;
+
+use crate::{
+    backends::software::SoftwareBackend,
+    brush::TBrush,
+    context::DrawState,
+    point::TPoint,
+    shapes::{RectRenderer, TinRect, TinRoundedRect},
+    vector2::TinVector2,
+    Double,
+};
+
+/// Number of straight segments used for each rounded corner.
+const CORNER_SEGMENTS: usize = 8;
+
+impl RectRenderer for SoftwareBackend {
+    fn rect_with_tinrect(&mut self, with_rect: &TinRect, brush: TBrush, state: DrawState) {
+        let (cx, cy) = (with_rect.center.get_x(), with_rect.center.get_y());
+        let w_offset = with_rect.get_width() / 2.0;
+        let h_offset = with_rect.get_height() / 2.0;
+        let points = [
+            TinVector2::from_xy(cx - w_offset, cy - h_offset),
+            TinVector2::from_xy(cx - w_offset, cy + h_offset),
+            TinVector2::from_xy(cx + w_offset, cy + h_offset),
+            TinVector2::from_xy(cx + w_offset, cy - h_offset),
+        ];
+        self.draw_shape(&points, true, brush, state);
+    }
+
+    fn rounded_rect(&mut self, rounded_rect: &TinRoundedRect, brush: TBrush, state: DrawState) {
+        let rect = &rounded_rect.rect;
+        let (cx, cy) = (rect.center.get_x(), rect.center.get_y());
+        let w_offset = rect.get_width() / 2.0;
+        let h_offset = rect.get_height() / 2.0;
+        let rx = rounded_rect.radius_x.abs().min(w_offset);
+        let ry = rounded_rect.radius_y.abs().min(h_offset);
+
+        // Corner centers, counterclockwise from the top right, with the angle each quarter arc starts at.
+        let corners = [
+            (cx + w_offset - rx, cy + h_offset - ry, 0.0),
+            (cx - w_offset + rx, cy + h_offset - ry, FRAC_PI_2),
+            (cx - w_offset + rx, cy - h_offset + ry, 2.0 * FRAC_PI_2),
+            (cx + w_offset - rx, cy - h_offset + ry, 3.0 * FRAC_PI_2),
+        ];
+        let mut points = Vec::with_capacity(corners.len() * (CORNER_SEGMENTS + 1));
+        for (x, y, start) in corners {
+            for i in 0..=CORNER_SEGMENTS {
+                let theta: Double = start + FRAC_PI_2 * i as Double / CORNER_SEGMENTS as Double;
+                points.push(TinVector2::from_xy(x + rx * theta.cos(), y + ry * theta.sin()));
+            }
+        }
+        self.draw_shape(&points, true, brush, state);
+    }
+}
@@ -0,0 +1,9 @@
+use crate::backends::{software::SoftwareBackend, StatefulRenderer};
+
+impl StatefulRenderer for SoftwareBackend {
+    // MARK: - Context state
+
+    fn push_state(&mut self) {}
+
+    fn pop_state(&mut self) {}
+}
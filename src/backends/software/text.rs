@@ -0,0 +1,42 @@
+use crate::{
+    backends::software::SoftwareBackend,
+    color::{TColor, TinColor},
+    context::DrawState,
+    point::{TPoint, TinPoint},
+    text::{TextRenderer, TinFont},
+    Double,
+};
+
+impl TextRenderer for SoftwareBackend {
+    fn text(
+        &mut self,
+        message: &String,
+        font: &TinFont,
+        center: TinPoint,
+        color: TinColor,
+        state: DrawState,
+    ) {
+        let bitmap = font.rasterize(message, &mut self.glyphs);
+        let left = center.get_x() + bitmap.left;
+        let top = center.get_y() + bitmap.top;
+        self.draw_sampled(
+            left,
+            top,
+            bitmap.width as Double,
+            bitmap.height as Double,
+            state,
+            |u, v| {
+                let coverage = bitmap.coverage_at(
+                    (u * bitmap.width as Double) as usize,
+                    (v * bitmap.height as Double) as usize,
+                );
+                if coverage <= 0.0 {
+                    return None;
+                }
+                let mut c = color;
+                c.set_alpha(color.get_alpha() * coverage as Double);
+                Some(c)
+            },
+        );
+    }
+}
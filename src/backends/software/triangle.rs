@@ -0,0 +1,19 @@
+use crate::{
+    backends::software::SoftwareBackend,
+    brush::TBrush,
+    context::DrawState,
+    point::TPoint,
+    shapes::{TinTriangle, TriangleRenderer},
+    vector2::TinVector2,
+};
+
+impl TriangleRenderer for SoftwareBackend {
+    fn triangle(&mut self, triangle: TinTriangle, brush: TBrush, state: DrawState) {
+        let points = [
+            TinVector2::from_xy(triangle.point1.get_x(), triangle.point1.get_y()),
+            TinVector2::from_xy(triangle.point2.get_x(), triangle.point2.get_y()),
+            TinVector2::from_xy(triangle.point3.get_x(), triangle.point3.get_y()),
+        ];
+        self.draw_shape(&points, true, brush, state);
+    }
+}
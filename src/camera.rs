@@ -0,0 +1,292 @@
+use crate::{
+    context::{get_tin, get_tin_mut, DrawState},
+    draw::DrawCall,
+    event::{TinEvent, TinMouseButton, TinScrollDelta},
+    point::{TPoint, TinPoint},
+    shapes::TinRect,
+    Double,
+};
+
+/// How much one line of mouse wheel scrolling zooms by default: 10% in or out.
+pub const DEFAULT_WHEEL_ZOOM_STEP: Double = 1.1;
+
+/// Pixels of trackpad scrolling that count as one line of mouse wheel scrolling.
+const PIXELS_PER_SCROLL_LINE: Double = 20.0;
+
+/**
+ A 2D camera looking at an infinite world. The world point at the camera's position is shown in the middle
+ of its viewport, magnified by the zoom and turned by minus the rotation, so the camera seems to turn.
+
+ Screen coordinates are the drawing coordinates of the window, in whatever coordinate space is set.
+ Drawing between `begin_camera` and `end_camera` is given in world coordinates.
+*/
+#[derive(Debug, Clone)]
+pub struct TinCamera2D {
+    position: TinPoint,
+    zoom: Double,
+    rotation: Double,
+    viewport: Option<TinRect>,
+    pan_button: Option<TinMouseButton>,
+    wheel_zoom_step: Option<Double>,
+    /// Where the mouse was last seen on screen, while panning or to zoom towards.
+    last_mouse: Option<TinPoint>,
+    panning: bool,
+}
+
+impl Default for TinCamera2D {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TinCamera2D {
+    /// A camera at the world origin, without zoom or rotation, showing the whole window.
+    pub fn new() -> Self {
+        Self {
+            position: TinPoint::default(),
+            zoom: 1.0,
+            rotation: 0.0,
+            viewport: None,
+            pan_button: None,
+            wheel_zoom_step: None,
+            last_mouse: None,
+            panning: false,
+        }
+    }
+
+    // MARK: - initializers
+
+    pub fn position(mut self, x: Double, y: Double) -> Self {
+        self.set_position(x, y);
+        self
+    }
+
+    pub fn zoom(mut self, zoom: Double) -> Self {
+        self.set_zoom(zoom);
+        self
+    }
+
+    pub fn rotation(mut self, rotation: Double) -> Self {
+        self.set_rotation(rotation);
+        self
+    }
+
+    /// Show the world in part of the window, given in screen coordinates, instead of the whole window.
+    pub fn viewport(mut self, viewport: TinRect) -> Self {
+        self.viewport = Some(viewport);
+        self
+    }
+
+    /// Pan by dragging with a mouse button, once events are passed to `handle_event`.
+    pub fn pan_with_mouse(mut self, button: TinMouseButton) -> Self {
+        self.pan_button = Some(button);
+        self
+    }
+
+    /**
+     Zoom towards the mouse with the wheel, once events are passed to `handle_event`.
+     Each line scrolled multiplies or divides the zoom by `step`, such as `DEFAULT_WHEEL_ZOOM_STEP`.
+    */
+    pub fn zoom_with_wheel(mut self, step: Double) -> Self {
+        self.wheel_zoom_step = Some(step).filter(|step| *step > 0.0);
+        self
+    }
+
+    // MARK: - state
+
+    pub fn get_position(&self) -> TinPoint {
+        self.position.clone()
+    }
+
+    pub fn set_position(&mut self, x: Double, y: Double) {
+        self.position = TinPoint::from_coords(x, y);
+    }
+
+    pub fn get_zoom(&self) -> Double {
+        self.zoom
+    }
+
+    /// Zooms that aren't positive are ignored.
+    pub fn set_zoom(&mut self, zoom: Double) {
+        if zoom > 0.0 {
+            self.zoom = zoom;
+        }
+    }
+
+    pub fn get_rotation(&self) -> Double {
+        self.rotation
+    }
+
+    pub fn set_rotation(&mut self, rotation: Double) {
+        self.rotation = rotation;
+    }
+
+    pub fn get_viewport(&self) -> Option<&TinRect> {
+        self.viewport.as_ref()
+    }
+
+    pub fn set_viewport(&mut self, viewport: Option<TinRect>) {
+        self.viewport = viewport;
+    }
+
+    /// Move the camera by a distance on screen, so the world follows a drag.
+    pub fn pan_by_screen(&mut self, dx: Double, dy: Double) {
+        let (world_dx, world_dy) = rotate(-dx / self.zoom, -dy / self.zoom, self.rotation);
+        self.position.translate(world_dx, world_dy);
+    }
+
+    /// Multiply the zoom by `factor`, keeping the world point under `screen_point` in place.
+    pub fn zoom_at(&mut self, factor: Double, screen_point: &impl TPoint) {
+        if factor <= 0.0 {
+            return;
+        }
+        let before = self.screen_to_world(screen_point);
+        self.zoom *= factor;
+        let after = self.screen_to_world(screen_point);
+        self.position.translate(
+            before.get_x() - after.get_x(),
+            before.get_y() - after.get_y(),
+        );
+    }
+
+    // MARK: - conversions
+
+    /**
+     The middle of the viewport, in screen coordinates, where the camera's position is shown.
+     Without a viewport of its own, that is the middle of the viewport being drawn in, or of the window.
+    */
+    pub fn get_viewport_center(&self) -> TinPoint {
+        match &self.viewport {
+            Some(viewport) => viewport.center.clone(),
+            None => {
+                let tin = get_tin();
+                let area = tin.drawing_area();
+                let (x, y) = tin
+                    .drawing_mapping()
+                    .inverse_point(area.center.get_x(), area.center.get_y());
+                TinPoint::from_coords(x, y)
+            }
+        }
+    }
+
+    /// Where a world point shows on screen.
+    pub fn world_to_screen(&self, point: &impl TPoint) -> TinPoint {
+        self.world_to_screen_around(point, &self.get_viewport_center())
+    }
+
+    /// Which world point shows at a screen point, such as the mouse position.
+    pub fn screen_to_world(&self, point: &impl TPoint) -> TinPoint {
+        self.screen_to_world_around(point, &self.get_viewport_center())
+    }
+
+    /// The world point under the mouse.
+    pub fn mouse_world_position(&self) -> TinPoint {
+        let mouse = get_tin().mouse_pos.clone();
+        self.screen_to_world(&mouse)
+    }
+
+    pub(crate) fn world_to_screen_around(&self, point: &impl TPoint, center: &TinPoint) -> TinPoint {
+        let (x, y) = rotate(
+            point.get_x() - self.position.get_x(),
+            point.get_y() - self.position.get_y(),
+            -self.rotation,
+        );
+        TinPoint::from_coords(
+            x * self.zoom + center.get_x(),
+            y * self.zoom + center.get_y(),
+        )
+    }
+
+    pub(crate) fn screen_to_world_around(&self, point: &impl TPoint, center: &TinPoint) -> TinPoint {
+        let (x, y) = rotate(
+            (point.get_x() - center.get_x()) / self.zoom,
+            (point.get_y() - center.get_y()) / self.zoom,
+            self.rotation,
+        );
+        TinPoint::from_coords(x + self.position.get_x(), y + self.position.get_y())
+    }
+
+    /**
+     The camera's transform from world to screen coordinates, as a draw state: `zoom (R(-rotation) p + t)`,
+     where t moves the camera's position to the viewport's center.
+    */
+    pub(crate) fn get_state_around(&self, center: &TinPoint) -> DrawState {
+        let (x, y) = rotate(self.position.get_x(), self.position.get_y(), -self.rotation);
+        DrawState {
+            rotation: -self.rotation,
+            scale: self.zoom,
+            translation: (
+                center.get_x() / self.zoom - x,
+                center.get_y() / self.zoom - y,
+            ),
+        }
+    }
+
+    // MARK: - mouse control
+
+    /**
+     Pan and zoom from mouse events, as turned on with `pan_with_mouse` and `zoom_with_wheel`.
+     Pass every event from `TScene::on_event`. Returns whether the camera used the event.
+    */
+    pub fn handle_event(&mut self, event: &TinEvent) -> bool {
+        match event {
+            TinEvent::MouseDown(mouse) if Some(mouse.button) == self.pan_button => {
+                self.panning = true;
+                self.last_mouse = Some(mouse.position.clone());
+                true
+            }
+            TinEvent::MouseUp(mouse) if Some(mouse.button) == self.pan_button => {
+                let was_panning = self.panning;
+                self.panning = false;
+                was_panning
+            }
+            TinEvent::MouseMoved(position) => {
+                let last = self.last_mouse.replace(position.clone());
+                match last {
+                    Some(last) if self.panning => {
+                        self.pan_by_screen(position.get_x() - last.get_x(), position.get_y() - last.get_y());
+                        true
+                    }
+                    _ => false,
+                }
+            }
+            TinEvent::MouseWheel(delta) => {
+                let (step, mouse) = match (self.wheel_zoom_step, &self.last_mouse) {
+                    (Some(step), Some(mouse)) => (step, mouse.clone()),
+                    _ => return false,
+                };
+                let lines = match delta {
+                    TinScrollDelta::Lines(_, y) => *y,
+                    TinScrollDelta::Pixels(_, y) => *y / PIXELS_PER_SCROLL_LINE,
+                };
+                self.zoom_at(step.powf(lines), &mouse);
+                true
+            }
+            TinEvent::WindowUnfocused => {
+                self.panning = false;
+                false
+            }
+            _ => false,
+        }
+    }
+}
+
+fn rotate(x: Double, y: Double, angle: Double) -> (Double, Double) {
+    let (sin, cos) = angle.sin_cos();
+    (x * cos - y * sin, x * sin + y * cos)
+}
+
+// MARK: - Global camera
+
+/// Draw in the camera's world coordinates until `end_camera`.
+pub fn begin_camera(camera: &TinCamera2D) {
+    let center = camera.get_viewport_center();
+    get_tin_mut()
+        .current_calls()
+        .push_back(DrawCall::Camera(Some(camera.get_state_around(&center))));
+}
+
+/// Go back to drawing in screen coordinates.
+pub fn end_camera() {
+    get_tin_mut().current_calls().push_back(DrawCall::Camera(None));
+}
@@ -1,9 +1,19 @@
 use super::{
     calculation::constrain,
-    draw::{fill_color_from_rgba, stroke_color_from_rgba},
+    draw::{fill_color_from_color, stroke_color_from_color},
     Double,
 };
 
+/// How the color functions in `draw`, such as `fill_color_from_rgba`, read their three color arguments.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TinColorMode {
+    /// Red, green and blue from 0 to 1. The default.
+    #[default]
+    Rgb,
+    /// Hue in degrees from 0 to 360, then saturation and brightness (value) from 0 to 1, as in Processing.
+    Hsb,
+}
+
 pub struct TPixel {
     pub location: [UInt; 2],
     pub red: u8,
@@ -257,6 +267,22 @@ pub trait TColor: Sized + Clone + PartialEq {
     fn from_rgb(red: Double, green: Double, blue: Double) -> Self {
         TColor::from_rgba(red, green, blue, 1.0)
     }
+    /// A color from its hue in degrees from 0 to 360, and its saturation and value from 0 to 1.
+    fn from_hsv(hue: Double, saturation: Double, value: Double) -> Self {
+        Self::from_hsva(hue, saturation, value, 1.0)
+    }
+    fn from_hsva(hue: Double, saturation: Double, value: Double, alpha: Double) -> Self {
+        let (red, green, blue) = hsv_to_rgb(hue, saturation, value);
+        Self::from_rgba(red, green, blue, alpha)
+    }
+    /// A color from its hue in degrees from 0 to 360, and its saturation and lightness from 0 to 1.
+    fn from_hsl(hue: Double, saturation: Double, lightness: Double) -> Self {
+        Self::from_hsla(hue, saturation, lightness, 1.0)
+    }
+    fn from_hsla(hue: Double, saturation: Double, lightness: Double, alpha: Double) -> Self {
+        let (red, green, blue) = hsl_to_rgb(hue, saturation, lightness);
+        Self::from_rgba(red, green, blue, alpha)
+    }
     fn from_pixel(pixel: TPixel) -> Self
     where
         Self: Sized,
@@ -277,9 +303,6 @@ pub trait TColor: Sized + Clone + PartialEq {
     fn set_green(&mut self, green: Double);
     fn set_blue(&mut self, blue: Double);
     fn set_alpha(&mut self, alpha: Double);
-    // TODO: provide a set implementation for hue, saturation, value
-    //       these write methods should then mutate red, green, blue
-
     // hue - a value from 0-360 https://en.wikipedia.org/wiki/HSL_and_HSV
     //
     fn get_hue(&self) -> Double {
@@ -334,22 +357,47 @@ pub trait TColor: Sized + Clone + PartialEq {
         return red.max(blue.max(green));
     }
 
-    // TODO: add an init for HSV inital values, maybe for grayscale too?
+    // The setters below keep the other HSV or HSL components, and mutate red, green and blue.
+    // A gray has no hue, so it stays gray when its hue is set.
+
+    /// Hue in degrees, wrapped into 0 to 360.
+    fn set_hue(&mut self, hue: Double) {
+        let rgb = hsv_to_rgb(hue, self.get_saturation(), self.get_value());
+        set_rgb(self, rgb);
+    }
+
+    /// Saturation as in HSV, from 0 to 1.
+    fn set_saturation(&mut self, saturation: Double) {
+        let rgb = hsv_to_rgb(self.get_hue(), saturation, self.get_value());
+        set_rgb(self, rgb);
+    }
+
+    /**
+     Value as in HSV, from 0 to 1.
+     Colors are stored as RGB, so black has no hue or saturation: after setting the value to 0,
+     raising it again gives a gray. Keep the hue and saturation yourself and use `from_hsv` instead.
+    */
+    fn set_value(&mut self, value: Double) {
+        let rgb = hsv_to_rgb(self.get_hue(), self.get_saturation(), value);
+        set_rgb(self, rgb);
+    }
+
+    /**
+     Lightness as in HSL, from 0 to 1, keeping the hue and the HSL saturation.
+     As with `set_value`, black and white have neither, so a lightness of 0 or 1 loses them.
+    */
+    fn set_lightness(&mut self, lightness: Double) {
+        let saturation = hsl_saturation(self.get_red(), self.get_green(), self.get_blue());
+        let rgb = hsl_to_rgb(self.get_hue(), saturation, lightness);
+        set_rgb(self, rgb);
+    }
 
     fn fill_color(&self) {
-        let r = self.get_red();
-        let g = self.get_green();
-        let b = self.get_blue();
-        let a = self.get_alpha();
-        fill_color_from_rgba(r, g, b, a);
+        fill_color_from_color(self.clone());
     }
 
     fn stroke_color(&self) {
-        let r = self.get_red();
-        let g = self.get_green();
-        let b = self.get_blue();
-        let a = self.get_alpha();
-        stroke_color_from_rgba(r, g, b, a);
+        stroke_color_from_color(self.clone());
     }
 
     // relative luminance https://en.wikipedia.org/wiki/Relative_luminance
@@ -369,3 +417,51 @@ pub trait TColor: Sized + Clone + PartialEq {
         return (self.get_red() + self.get_green() + self.get_blue()) / 3.0;
     }
 }
+
+fn set_rgb<C: TColor>(color: &mut C, (red, green, blue): (Double, Double, Double)) {
+    color.set_red(red);
+    color.set_green(green);
+    color.set_blue(blue);
+}
+
+/// Red, green and blue from a hue in degrees, and a saturation and value from 0 to 1. https://en.wikipedia.org/wiki/HSL_and_HSV
+pub(crate) fn hsv_to_rgb(hue: Double, saturation: Double, value: Double) -> (Double, Double, Double) {
+    let saturation = constrain(saturation, 0.0, 1.0);
+    let value = constrain(value, 0.0, 1.0);
+    let chroma = value * saturation;
+    rgb_from_chroma(hue, chroma, value - chroma)
+}
+
+pub(crate) fn hsl_to_rgb(hue: Double, saturation: Double, lightness: Double) -> (Double, Double, Double) {
+    let saturation = constrain(saturation, 0.0, 1.0);
+    let lightness = constrain(lightness, 0.0, 1.0);
+    let chroma = (1.0 - (2.0 * lightness - 1.0).abs()) * saturation;
+    rgb_from_chroma(hue, chroma, lightness - chroma / 2.0)
+}
+
+/// Saturation as in HSL, which unlike the HSV saturation of `get_saturation` is relative to the lightness.
+pub(crate) fn hsl_saturation(red: Double, green: Double, blue: Double) -> Double {
+    let max_m = red.max(blue.max(green));
+    let min_m = red.min(blue.min(green));
+    let lightness = 0.5 * (max_m + min_m);
+    let divisor = 1.0 - (2.0 * lightness - 1.0).abs();
+    if divisor < 0.00001 {
+        return 0.0;
+    }
+    (max_m - min_m) / divisor
+}
+
+/// The color of a hue with `chroma` between its strongest and weakest component, raised by `offset`.
+fn rgb_from_chroma(hue: Double, chroma: Double, offset: Double) -> (Double, Double, Double) {
+    let sector = hue.rem_euclid(360.0) / 60.0;
+    let x = chroma * (1.0 - (sector % 2.0 - 1.0).abs());
+    let (red, green, blue) = match sector as UInt {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+    (red + offset, green + offset, blue + offset)
+}
@@ -1,11 +1,11 @@
 
-use crate::{CurrentBackend, Double, ULong, backends::TBackend, brush::TBrush, color::{
+use crate::{CurrentBackend, Double, UInt, ULong, backends::TBackend, brush::TBrush, color::{
         DEFAULT_COLOR_FILL, DEFAULT_COLOR_STROKE, DEFAULT_COLOR_BACKGROUND,
         TColor, TinColor
-    }, draw::DrawCall, frame::TinFrame, point::TPoint, point::TinPoint};
+    }, draw::DrawCall, event::TinEvent, frame::TinFrame, point::TPoint, point::TinPoint, scene::TScene, window::TinWindowId};
 
 use lazy_static;
-use std::{collections::VecDeque, sync::{
+use std::{collections::{HashMap, VecDeque}, time::Duration, sync::{
     RwLock,
     RwLockWriteGuard,
     RwLockReadGuard
@@ -25,7 +25,130 @@ pub(crate) fn get_tin_mut<'t>() -> RwLockWriteGuard<'t, TinContext<CurrentBacken
     return TIN_LOCK.write().unwrap();
 }
 
+/**
+ Update the context from an event, then pass it on to the scene, followed by any action events it caused.
+ Backends send every event through here, with mouse positions from the middle of the window and y growing upwards.
+*/
+pub(crate) fn dispatch_event<S: TScene + ?Sized>(scene: &mut S, event: TinEvent) {
+    dispatch_window_event(scene, TinWindowId::MAIN, event);
+}
+
+/**
+ Like `dispatch_event`, for an event from one of the app's windows. The window's size is used while the event
+ is handled, and `current_window` names it until the scene returns.
+*/
+pub(crate) fn dispatch_window_event<S: TScene + ?Sized>(scene: &mut S, window: TinWindowId, event: TinEvent) {
+    let (event, action_events) = {
+        let mut tin = get_tin_mut();
+        #[cfg(feature = "recording")]
+        {
+            if tin.recording.ignores_live_event(&event) {
+                return;
+            }
+            // Recordings play back into the main window, so only its events are kept.
+            if window.is_main() {
+                let frame = tin.frame_count;
+                tin.recording.record(frame, &event);
+            }
+        }
+        tin.use_window(window);
+        let event = tin.event_in_drawing_space(event);
+        let action_events = tin.handle_event(&event);
+        (event, action_events)
+    };
+    deliver_event(scene, window, event, action_events);
+    if !window.is_main() {
+        get_tin_mut().use_window(TinWindowId::MAIN);
+    }
+}
+
+/// Call the scene's hook for an event, then pass it and the action events it caused to `on_window_event`.
+fn deliver_event<S: TScene + ?Sized>(scene: &mut S, window: TinWindowId, event: TinEvent, action_events: Vec<TinEvent>) {
+    match &event {
+        TinEvent::WindowResized(width, height) => scene.on_resize(*width, *height),
+        TinEvent::WindowFocused => scene.on_focus_change(true),
+        TinEvent::WindowUnfocused => scene.on_focus_change(false),
+        _ => {}
+    }
+    scene.on_window_event(window, event);
+    for action_event in action_events {
+        scene.on_window_event(window, action_event);
+    }
+}
+
+/// Deliver the events of a playing recording that are due, before the next update.
+#[cfg(feature = "recording")]
+fn dispatch_recorded_events<S: TScene>(scene: &mut S) {
+    let events = {
+        let mut tin = get_tin_mut();
+        let frame = tin.frame_count;
+        tin.recording.take_due_events(frame)
+    };
+    for event in events {
+        let (event, action_events) = {
+            let mut tin = get_tin_mut();
+            let event = tin.event_in_drawing_space(event);
+            let action_events = tin.handle_event(&event);
+            (event, action_events)
+        };
+        deliver_event(scene, TinWindowId::MAIN, event, action_events);
+    }
+}
+
+/**
+ Run one frame of the scene: recorded events that are due, the pause or resume hook if that changed,
+ every fixed update that is due, `update`, then `draw`. `delta` is the time since the previous frame started.
+ While paused the frame clock stands still. Backends process the draw calls afterwards.
+*/
+pub(crate) fn update_scene<S: TScene>(scene: &mut S, delta: Duration) {
+    #[cfg(feature = "recording")]
+    dispatch_recorded_events(scene);
+    let frame = get_tin_mut().begin_scene_frame(delta);
+    run_scene_frame(scene, frame);
+}
+
+/**
+ Run one frame of a window's own scene, after the main scene's frame. It runs the same fixed updates,
+ and pauses and resumes with the main scene. Drawing is sized by the window until the next `use_window`.
+*/
+pub(crate) fn update_window_scene(scene: &mut dyn TScene, window: TinWindowId) {
+    let frame = get_tin_mut().window_scene_frame(window);
+    run_scene_frame(scene, frame);
+}
+
+/// What a scene runs in one frame, decided by the context before the scene is called.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct TinSceneFrame {
+    pub fixed_steps: UInt,
+    pub step: Double,
+    pub paused: bool,
+    /// Whether the scene needs to hear that it was paused or resumed.
+    pub pause_changed: bool,
+}
 
+/// Call the pause or resume hook if that changed, then every fixed update that is due and `update` unless paused, then `draw`.
+pub(crate) fn run_scene_frame<S: TScene + ?Sized>(scene: &mut S, frame: TinSceneFrame) {
+    if frame.pause_changed {
+        if frame.paused {
+            scene.on_pause();
+        } else {
+            scene.on_resume();
+        }
+    }
+    if !frame.paused {
+        for _ in 0..frame.fixed_steps {
+            scene.fixed_update(frame.step);
+        }
+        scene.update();
+    }
+    scene.draw();
+}
+
+/// Draw the app's scene again for another window that shares it. The scene tells them apart with `current_window`.
+pub(crate) fn draw_shared_window<S: TScene + ?Sized>(scene: &mut S, window: TinWindowId) {
+    get_tin_mut().use_window(window);
+    scene.draw();
+}
 
 #[derive(Debug, Clone, Copy)]
 pub(crate) struct DrawState {
@@ -44,6 +167,37 @@ impl Default for DrawState {
     }
 }
 
+impl DrawState {
+    /// Maps a point through this state's rotation, then translation, then scale.
+    pub(crate) fn apply(&self, x: Double, y: Double) -> (Double, Double) {
+        let (sin, cos) = self.rotation.sin_cos();
+        let rotated_x = x * cos - y * sin;
+        let rotated_y = x * sin + y * cos;
+        (
+            (rotated_x + self.translation.0) * self.scale,
+            (rotated_y + self.translation.1) * self.scale,
+        )
+    }
+
+    /// The state that maps a point through `inner` first, then through this one.
+    pub(crate) fn compose(&self, inner: DrawState) -> DrawState {
+        let (sin, cos) = self.rotation.sin_cos();
+        let (x, y) = inner.translation;
+        let rotated = (x * cos - y * sin, x * sin + y * cos);
+        // With no scale inside, every point lands on this state's translation, which can't be carried over.
+        let carried = if inner.scale == 0.0 {
+            (0.0, 0.0)
+        } else {
+            (self.translation.0 / inner.scale, self.translation.1 / inner.scale)
+        };
+        DrawState {
+            rotation: self.rotation + inner.rotation,
+            scale: self.scale * inner.scale,
+            translation: (rotated.0 + carried.0, rotated.1 + carried.1),
+        }
+    }
+}
+
 type DrawQueue = VecDeque<crate::draw::DrawCall>;
 
 pub(crate) struct TinContext<T: TBackend> {
@@ -52,14 +206,44 @@ pub(crate) struct TinContext<T: TBackend> {
     pub height: Double,
     pub mid_x: Double,
     pub mid_y: Double,
+    /// Pixels per point of the window's screen, 2.0 on most HiDPI screens.
+    pub(crate) pixel_density: Double,
+    pub(crate) coordinate_space: crate::space::TinCoordinateSpace,
+    pub(crate) rect_mode: crate::space::TinShapeMode,
+    pub(crate) ellipse_mode: crate::space::TinShapeMode,
+    pub(crate) color_mode: crate::color::TinColorMode,
+    /// The viewport drawing calls are being queued in, if any.
+    pub(crate) viewport: Option<crate::viewport::TinViewport>,
     pub mouse_pos: TinPoint,
     pub prev_mouse_pos: TinPoint,
     pub mouse_pressed: bool,
+    pub(crate) input: crate::input::TinInputState,
+    pub(crate) actions: crate::input::TinActionState,
+    #[cfg(feature = "recording")]
+    pub(crate) recording: crate::recording::TinRecordingState,
     frame_count: ULong,
+    pub(crate) clock: crate::timing::TinFrameClock,
+    pub(crate) paused: bool,
+    /// Whether the scene was last told it is paused, so the hooks are called once per change.
+    scene_paused: bool,
+    /// Whether the current frame told the scene it was paused or resumed, so windows with their own scene follow.
+    pause_changed: bool,
+    pub(crate) quit_requested: bool,
+    pub(crate) scene_commands: Vec<crate::scene::TinSceneCommand>,
+    pub(crate) view: crate::view::TinView,
+    /// Changes asked for since the last frame, each with the window that was current when it was asked for.
+    pub(crate) window_requests: Vec<(TinWindowId, crate::view::TinWindowRequest)>,
+    /// The window events are being handled for or drawing is being done in.
+    pub(crate) current_window: TinWindowId,
+    /// The size in points of every open window.
+    pub(crate) window_sizes: HashMap<TinWindowId, (Double, Double)>,
+    /// Windows besides the main one, set by `Tin::run` until the backend opens them.
+    pub(crate) extra_windows: Vec<crate::window::TinWindowConfig>,
 
     pub state: DrawState,
 
-    pub(crate) calls: DrawQueue,
+    /// Draw calls queued for each window, until the window is drawn.
+    pub(crate) calls: HashMap<TinWindowId, DrawQueue>,
 
     pub fill: bool,
     pub stroke: bool,
@@ -71,6 +255,9 @@ pub(crate) struct TinContext<T: TBackend> {
 
     pub(crate) path_vertex_count: ULong,
 
+    #[cfg(feature = "image")]
+    pub(crate) images: crate::image::TinImageStore,
+
     pub(crate) render: Box<T>
 }
 
@@ -86,10 +273,31 @@ impl<T: TBackend> TinContext<T> {
             height: 0.0,
             mid_x: 0.0,
             mid_y: 0.0,
+            pixel_density: 1.0,
+            coordinate_space: crate::space::TinCoordinateSpace::default(),
+            rect_mode: crate::space::TinShapeMode::Center,
+            ellipse_mode: crate::space::TinShapeMode::Center,
+            color_mode: crate::color::TinColorMode::default(),
+            viewport: None,
             mouse_pos: TinPoint::default(),
             prev_mouse_pos: TinPoint::default(),
             mouse_pressed: false,
+            input: crate::input::TinInputState::new(),
+            actions: crate::input::TinActionState::new(),
+            #[cfg(feature = "recording")]
+            recording: crate::recording::TinRecordingState::new(),
             frame_count: 0,
+            clock: crate::timing::TinFrameClock::new(),
+            paused: false,
+            scene_paused: false,
+            pause_changed: false,
+            quit_requested: false,
+            scene_commands: Vec::new(),
+            view: <crate::view::TinView as crate::view::TView>::new("Default Title", TinFrame::default()),
+            window_requests: Vec::new(),
+            current_window: TinWindowId::MAIN,
+            window_sizes: HashMap::new(),
+            extra_windows: Vec::new(),
 
             state: DrawState {
                 rotation: 0.0,
@@ -97,7 +305,7 @@ impl<T: TBackend> TinContext<T> {
                 translation: (0.0,0.0),
             },
 
-            calls: VecDeque::new(),
+            calls: HashMap::new(),
 
             current_fill_color: DEFAULT_COLOR_FILL,
             current_stroke_color: DEFAULT_COLOR_STROKE,
@@ -107,6 +315,9 @@ impl<T: TBackend> TinContext<T> {
 
             path_vertex_count: 0,
 
+            #[cfg(feature = "image")]
+            images: crate::image::TinImageStore::new(),
+
             render: Box::new(T::new())
         }
     }
@@ -137,6 +348,7 @@ impl<T: TBackend> TinContext<T> {
     /// TODO: Document this method.
     pub fn prepare_for_update(&mut self) {
         self.reset(self.width, self.height);
+        self.viewport = None;
         self.update_frame_count();
         {
             assert_eq!(self.current_fill_color, DEFAULT_COLOR_FILL);
@@ -149,6 +361,9 @@ impl<T: TBackend> TinContext<T> {
     /// TODO: Document this method.
     pub fn did_finish_update(&mut self) {
         self.render.did_finish_update();
+        self.input.end_frame();
+        self.actions.end_frame();
+        self.prev_mouse_pos = self.mouse_pos.clone();
     }
 
     /// TODO: Document this method.
@@ -158,6 +373,89 @@ impl<T: TBackend> TinContext<T> {
         self.height = height;
         self.mid_x = width / 2.0;
         self.mid_y = height / 2.0;
+        self.window_sizes.insert(self.current_window, (width, height));
+        self.render.resize(width, height);
+    }
+
+    /**
+     Start a frame of the main scene: reset drawing, note whether the pause state changed since the scene
+     was last told, and advance the frame clock by `delta`, or not at all while paused.
+    */
+    pub(crate) fn begin_scene_frame(&mut self, delta: Duration) -> TinSceneFrame {
+        self.use_window(TinWindowId::MAIN);
+        self.prepare_for_update();
+        let paused = self.paused;
+        let pause_changed = paused != self.scene_paused;
+        self.scene_paused = paused;
+        self.pause_changed = pause_changed;
+        let frame = self.frame_count;
+        let delta = if paused { 0.0 } else { delta.as_secs_f64() };
+        let (fixed_steps, step) = self.clock.tick(delta, frame);
+        TinSceneFrame { fixed_steps, step, paused, pause_changed }
+    }
+
+    /// The frame a window's own scene runs, matching the one `begin_scene_frame` started.
+    pub(crate) fn window_scene_frame(&mut self, window: TinWindowId) -> TinSceneFrame {
+        self.use_window(window);
+        let (fixed_steps, step) = self.clock.get_last_steps();
+        TinSceneFrame { fixed_steps, step, paused: self.paused, pause_changed: self.pause_changed }
+    }
+
+    /// Handle events and size drawing for `window`. A window that isn't open yet keeps the current size.
+    pub(crate) fn use_window(&mut self, window: TinWindowId) {
+        self.current_window = window;
+        if let Some((width, height)) = self.window_sizes.get(&window).copied() {
+            self.reset_size(width, height);
+        }
+        self.viewport = None;
+    }
+
+    /// The draw calls queued for `current_window`.
+    pub(crate) fn current_calls(&mut self) -> &mut DrawQueue {
+        self.calls.entry(self.current_window).or_default()
+    }
+
+    /// Maps the coordinate space drawing and mouse positions use onto the one backends draw in.
+    pub(crate) fn space_mapping(&self) -> crate::space::TinSpaceMapping {
+        crate::space::TinSpaceMapping::new(self.coordinate_space, self.width, self.height)
+    }
+
+    /// Maps the coordinates drawing uses right now: those of the viewport being drawn in, or of the window.
+    pub(crate) fn drawing_mapping(&self) -> crate::space::TinSpaceMapping {
+        let window_mapping = self.space_mapping();
+        match &self.viewport {
+            Some(viewport) => viewport.local_mapping(&window_mapping),
+            None => window_mapping,
+        }
+    }
+
+    /// The viewport being drawn in, or the whole window, in backend coordinates.
+    pub(crate) fn drawing_area(&self) -> crate::shapes::TinRect {
+        match &self.viewport {
+            Some(viewport) => self.space_mapping().rect(viewport.get_rect()),
+            None => crate::shapes::TinRect::from_dimensions(0.0, 0.0, self.width, self.height),
+        }
+    }
+
+    /// An event from a backend, with its mouse position moved into the coordinate space the scene draws in.
+    pub(crate) fn event_in_drawing_space(&self, event: TinEvent) -> TinEvent {
+        let mapping = self.space_mapping();
+        let to_drawing_space = |point: TinPoint| {
+            let (x, y) = mapping.inverse_point(point.get_x(), point.get_y());
+            TinPoint::from_coords(x, y)
+        };
+        match event {
+            TinEvent::MouseMoved(point) => TinEvent::MouseMoved(to_drawing_space(point)),
+            TinEvent::MouseDown(mut mouse) => {
+                mouse.position = to_drawing_space(mouse.position);
+                TinEvent::MouseDown(mouse)
+            }
+            TinEvent::MouseUp(mut mouse) => {
+                mouse.position = to_drawing_space(mouse.position);
+                TinEvent::MouseUp(mouse)
+            }
+            event => event,
+        }
     }
 
 
@@ -181,11 +479,32 @@ impl<T: TBackend> TinContext<T> {
 
     /// TODO: Document this function.
     pub fn mouse_moved(&mut self, to_point: impl TPoint) {
-        self.prev_mouse_pos = self.mouse_pos.clone();
-        {
-            self.mouse_pos.set_x(to_point.get_x());
-            self.mouse_pos.set_y(to_point.get_y());
+        self.mouse_pos.set_x(to_point.get_x());
+        self.mouse_pos.set_y(to_point.get_y());
+    }
+
+    /**
+     Update the input state, mouse position and window size from an event, before the scene sees it.
+     Returns the action events the event caused.
+    */
+    pub fn handle_event(&mut self, event: &TinEvent) -> Vec<TinEvent> {
+        match event {
+            TinEvent::WindowResized(width, height) => {
+                self.reset_size(*width as Double, *height as Double);
+                if self.current_window.is_main() {
+                    self.view.set_frame(TinFrame::new(*width, *height));
+                }
+            }
+            TinEvent::WindowPixelDensityChanged(density) => self.pixel_density = *density,
+            TinEvent::MouseMoved(point) => self.mouse_moved(point.clone()),
+            TinEvent::MouseDown(mouse) | TinEvent::MouseUp(mouse) => {
+                self.mouse_moved(mouse.position.clone())
+            }
+            _ => {}
         }
+        self.input.handle_event(event);
+        self.mouse_pressed = self.input.any_button_down();
+        self.actions.handle_event(event)
     }
 
     pub fn get_frame_count(&self) -> ULong {
@@ -228,10 +547,19 @@ impl<T: TBackend> TinContext<T> {
     }
 
 
+    /// Hand the calls queued for `current_window` to the backend. Other windows' calls stay queued.
     pub fn process_draw_calls(&mut self) {
+        // Shapes and transforms are mapped from the drawing space as they are handed to the backend.
+        let window_mapping = self.space_mapping();
+        let mut mapping = window_mapping;
+        let mut viewport_rect: Option<crate::shapes::TinRect> = None;
+        let mut calls: DrawQueue = std::mem::take(self.current_calls());
         let render = &mut self.render;
 
-        let calls: &mut DrawQueue = &mut self.calls;
+        #[cfg(feature = "image")]
+        for image_id in self.images.drain_evicted() {
+            render.forget_image(image_id);
+        }
 
         let mut fill_color = DEFAULT_COLOR_FILL;
         let mut stroke_color = DEFAULT_COLOR_STROKE;
@@ -239,21 +567,38 @@ impl<T: TBackend> TinContext<T> {
         let mut line_width = self.line_width;
         let mut state = DrawState::default();
         let mut pushed_state = None;
+        let mut camera: Option<DrawState> = None;
+        let mut opacity: Double = 1.0;
+        let fade = |color: TinColor, opacity: Double| {
+            let mut faded = color;
+            faded.set_alpha(color.get_alpha() * opacity);
+            faded
+        };
         //let mut path_vertices = Vec::<crate::vertex::TinVertex>::new();
         while calls.len() > 0 {
             let call = calls.pop_front().expect("Draw call list should have had a value.");
+            let mapped_state = mapping.state(camera.map_or(state, |camera| camera.compose(state)));
 
+            let (faded_fill, faded_stroke) = (fade(fill_color, opacity), fade(stroke_color, opacity));
             let brush = if should_fill && should_stroke {
-                TBrush::FillAndStroke(fill_color, stroke_color)
+                TBrush::FillAndStroke(faded_fill, faded_stroke)
             } else if should_fill && !should_stroke {
-                TBrush::Fill(fill_color)
+                TBrush::Fill(faded_fill)
             } else if !should_fill && should_stroke {
-                TBrush::Stroke(stroke_color)
+                TBrush::Stroke(faded_stroke)
             } else {
                 TBrush::Disabled
             };
             match call {
-                DrawCall::Background(color) => self.current_background_color = color,
+                // A faded background can't be the clear color, and one in a viewport only clears the viewport, so they are painted over instead.
+                DrawCall::Background(color) if opacity < 1.0 || viewport_rect.is_some() => {
+                    let area = viewport_rect.clone().unwrap_or_else(|| crate::shapes::TinRect::from_dimensions(0.0, 0.0, self.width, self.height));
+                    render.rect_with_tinrect(&area, TBrush::Fill(fade(color, opacity)), DrawState::default());
+                },
+                DrawCall::Background(color) => {
+                    self.current_background_color = color;
+                    render.background(color);
+                },
                 DrawCall::Fill(color) => fill_color = color,
                 DrawCall::Stroke(color) => stroke_color = color,
                 DrawCall::SetAlpha(alpha) => {
@@ -262,6 +607,20 @@ impl<T: TBackend> TinContext<T> {
                 },
 
                 DrawCall::PushState => pushed_state = Some(state),
+                DrawCall::ResetState => {
+                    fill_color = DEFAULT_COLOR_FILL;
+                    stroke_color = DEFAULT_COLOR_STROKE;
+                    should_fill = true;
+                    should_stroke = true;
+                    line_width = self.line_width;
+                    state = DrawState::default();
+                    pushed_state = None;
+                    camera = None;
+                    mapping = window_mapping;
+                    viewport_rect = None;
+                    render.set_clip(None);
+                },
+                DrawCall::Opacity(amount) => opacity = amount.clamp(0.0, 1.0),
                 DrawCall::PopState => {
                     match pushed_state {
                         Some(s) => {state = s; pushed_state = None},
@@ -274,30 +633,69 @@ impl<T: TBackend> TinContext<T> {
                 },
                 DrawCall::Rotate(theta) => state.rotation += theta.clone(),
                 DrawCall::Scale(amount) => state.scale += amount.clone(),
+                DrawCall::Camera(camera_state) => camera = camera_state,
+                DrawCall::Viewport(Some(viewport)) => {
+                    let rect = window_mapping.rect(viewport.get_rect());
+                    mapping = viewport.local_mapping(&window_mapping);
+                    if let Some(color) = viewport.get_background() {
+                        render.rect_with_tinrect(&rect, TBrush::Fill(fade(color, opacity)), DrawState::default());
+                    }
+                    render.set_clip(if viewport.is_clipped() { Some(rect.clone()) } else { None });
+                    viewport_rect = Some(rect);
+                },
+                DrawCall::Viewport(None) => {
+                    mapping = window_mapping;
+                    viewport_rect = None;
+                    render.set_clip(None);
+                },
                 DrawCall::LineWidth(width) => line_width = width,
 
-                DrawCall::Arc(arc) => render.arc(arc, brush, state),
-                DrawCall::Ellipse(rect) => render.ellipse_in_tinrect(&rect, brush, state),
-                DrawCall::Line(point1, point2) => render.line(point1, point2, line_width, brush, state),
-                DrawCall::Rect(rect) => render.rect_with_tinrect(&rect, brush, state),
-                DrawCall::RoundedRect(rounded_rect) => render.rounded_rect(&rounded_rect, brush, state),
-                DrawCall::Triangle(triangle) => render.triangle(triangle, brush, state),
-                DrawCall::PathBegin => todo!(),
-                DrawCall::PathVertex(point) => {render.path_vertex(&point); self.path_vertex_count += 1},
-                DrawCall::PathAddCurve(_wrapper) => {/* render.path_add_curve(); */self.path_vertex_count += 4;todo!()},
-                DrawCall::PathEnd => {self.path_vertex_count = 0; /* path_vertices.clear()*/ },
+                DrawCall::Arc(arc) => render.arc(mapping.arc(&arc), brush, mapped_state),
+                DrawCall::Ellipse(rect) => render.ellipse_in_tinrect(&mapping.rect(&rect), brush, mapped_state),
+                DrawCall::Line(point1, point2) => render.line(mapping.tin_point(&point1), mapping.tin_point(&point2), mapping.length(line_width), brush, mapped_state),
+                DrawCall::Rect(rect) => render.rect_with_tinrect(&mapping.rect(&rect), brush, mapped_state),
+                DrawCall::RoundedRect(rounded_rect) => {
+                    let rounded_rect = crate::shapes::TinRoundedRect::new(mapping.rect(&rounded_rect.rect), mapping.length(rounded_rect.radius_x), mapping.length(rounded_rect.radius_y));
+                    render.rounded_rect(&rounded_rect, brush, mapped_state)
+                },
+                DrawCall::Triangle(triangle) => render.triangle(mapping.triangle(&triangle), brush, mapped_state),
+                DrawCall::PathBegin => {render.path_begin(); self.path_vertex_count = 0},
+                DrawCall::PathVertex(point) => {render.path_vertex(&mapping.tin_point(&point)); self.path_vertex_count += 1},
+                DrawCall::PathAddCurve(wrapper) => {
+                    let (to, control1, control2) = (wrapper.to, wrapper.control1, wrapper.control2);
+                    render.path_add_curve(&mapping.point(to.0, to.1), &mapping.point(control1.0, control1.1), &mapping.point(control2.0, control2.1));
+                    self.path_vertex_count += 3
+                },
+                DrawCall::PathContour => render.path_contour(),
+                DrawCall::PathEnd => {render.path_end(brush, mapped_state); self.path_vertex_count = 0},
                 DrawCall::FillEnable => should_fill = true,
                 DrawCall::FillDisable => should_fill = false,
                 DrawCall::StrokeEnable => should_stroke = true,
                 DrawCall::StrokeDisable => should_stroke = false,
-                #[cfg(image)]
-                DrawCall::Image(wrapper) => render.image_with_size_and_resize(wrapper.image, wrapper.center, wrapper.width, wrapper.height, wrapper.resize, state),
-                #[cfg(text)]
-                DrawCall::Text(_) => todo!(),
+                DrawCall::DebugText(wrapper) => {
+                    if should_fill {
+                        // Laid out after mapping, so lines run down the window whichever way y grows.
+                        let origin = mapping.tin_point(&wrapper.origin);
+                        let rects = crate::debug_text::debug_text_rects(&wrapper.message, origin.get_x(), origin.get_y(), mapping.length(wrapper.scale));
+                        for rect in rects {
+                            render.rect_with_tinrect(&rect, TBrush::Fill(faded_fill), mapped_state);
+                        }
+                    }
+                },
+                #[cfg(feature = "image")]
+                DrawCall::Image(wrapper) => render.image_with_size_and_resize(&wrapper.image, mapping.tin_point(&wrapper.center), mapping.length(wrapper.width), mapping.length(wrapper.height), wrapper.resize, mapped_state),
+                #[cfg(feature = "text")]
+                DrawCall::Text(wrapper) => {
+                    if should_fill {
+                        render.text(&wrapper.message, &wrapper.font, mapping.tin_point(&wrapper.center), faded_fill, mapped_state)
+                    }
+                },
 
                 call => eprintln!("{:?}",call)
             }
         }
+        // A viewport left open doesn't clip the next frame.
+        render.set_clip(None);
     }
 
 
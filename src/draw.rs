@@ -2,9 +2,10 @@
 
 use crate::{
     color::*,
-    context::get_tin,
+    context::{get_tin, get_tin_mut},
     point::{TPoint, TinPoint},
     shapes::*,
+    space::TinShapeMode,
     Double,
 };
 
@@ -16,14 +17,31 @@ use crate::{
 
 // MARK: - Color state
 
-/// TODO: Document this function.
+/**
+ How `fill_color_from_rgba`, `stroke_color_from_rgba` and `background` read their color arguments from now on.
+ With `TinColorMode::Hsb`, they are hue in degrees, saturation and brightness. Grays and colors are unaffected.
+*/
+pub fn color_mode(mode: TinColorMode) {
+    get_tin_mut().color_mode = mode;
+}
+
+/// A color from three arguments read in the current color mode.
+fn color_from_components(first: Double, second: Double, third: Double, alpha: Double) -> TinColor {
+    let mode = get_tin().color_mode;
+    match mode {
+        TinColorMode::Rgb => TinColor::from_rgba(first, second, third, alpha),
+        TinColorMode::Hsb => TinColor::from_hsva(first, second, third, alpha),
+    }
+}
+
+/// Red, green and blue, or hue, saturation and brightness with `TinColorMode::Hsb`.
 pub fn stroke_color_from_rgba(red: Double, green: Double, blue: Double, alpha: Double) {
-    stroke_color_from_color(TinColor::from_rgba(red, green, blue, alpha))
+    stroke_color_from_color(color_from_components(red, green, blue, alpha))
 }
 
 /// TODO: Document this function.
 pub fn stroke_color_from_gray_and_alpha(gray: Double, alpha: Double) {
-    stroke_color_from_rgba(gray, gray, gray, alpha);
+    stroke_color_from_color(TinColor::from_rgba(gray, gray, gray, alpha));
 }
 
 /// TODO: Document this function.
@@ -36,14 +54,14 @@ pub fn stroke_color_from_color(color: impl TColor) {
     add_draw_call(DrawCall::Stroke(TinColor::from(color)))
 }
 
-/// TODO: Document this function.
+/// Red, green and blue, or hue, saturation and brightness with `TinColorMode::Hsb`.
 pub fn fill_color_from_rgba(red: Double, green: Double, blue: Double, alpha: Double) {
-    fill_color_from_color(TinColor::from_rgba(red, green, blue, alpha))
+    fill_color_from_color(color_from_components(red, green, blue, alpha))
 }
 
 /// TODO: Document this function.
 pub fn fill_color_from_gray_and_alpha(gray: Double, alpha: Double) {
-    fill_color_from_rgba(gray, gray, gray, alpha)
+    fill_color_from_color(TinColor::from_rgba(gray, gray, gray, alpha))
 }
 
 /// TODO: Document this function.
@@ -71,14 +89,14 @@ pub fn set_alpha(alpha: Double) {
     add_draw_call(DrawCall::SetAlpha(alpha))
 }
 
-/// Clear (erase) the background
+/// Clear (erase) the background. Hue, saturation and brightness with `TinColorMode::Hsb`.
 pub fn background(red: Double, green: Double, blue: Double) {
-    background_with_tin_color(TinColor::from_rgba(red, green, blue, 1.0))
+    background_with_tin_color(color_from_components(red, green, blue, 1.0))
 }
 
 /// TODO: Document this function.
 pub fn background_gray(gray: Double) {
-    background(gray, gray, gray)
+    background_with_tin_color(TinColor::from_rgba(gray, gray, gray, 1.0))
 }
 
 /// TODO: Document this function.
@@ -117,14 +135,19 @@ pub fn arc(x: Double, y: Double, radius: Double, start_angle: Double, end_angle:
 
 // Ellipse method
 
-/// Draw an ellipse. Input is centerX, centerY coordinate and width, height size.
-pub fn ellipse(center_x: Double, center_y: Double, width: Double, height: Double) {
-    let x = center_x - width / 2.0;
-    let y = center_y - height / 2.0;
-    let r = TinRect::from_dimensions(x, y, width, height);
+/// Draw an ellipse of width, height size, placed at x, y according to `ellipse_mode`: its center by default.
+pub fn ellipse(x: Double, y: Double, width: Double, height: Double) {
+    let mode = get_tin().ellipse_mode;
+    let (center_x, center_y) = mode.center_of(x, y, width, height);
+    let r = TinRect::from_dimensions(center_x, center_y, width, height);
     add_draw_call(DrawCall::Ellipse(r))
 }
 
+/// Which point of an ellipse the x, y given to `ellipse` place.
+pub fn ellipse_mode(mode: TinShapeMode) {
+    get_tin_mut().ellipse_mode = mode;
+}
+
 // Line methods
 
 /// TODO: Document this function.
@@ -141,12 +164,22 @@ pub fn line_width(width: Double) {
 
 // Rectangle method
 
-/// Draw a rectangle. Input is left, bottom coordinate and width, height size.
+/// Draw a rectangle of width, height size, placed at x, y according to `rect_mode`: its center by default.
 pub fn rect(x: Double, y: Double, width: Double, height: Double) {
-    let r = TinRect::from_dimensions(x, y, width, height);
+    let mode = get_tin().rect_mode;
+    let (center_x, center_y) = mode.center_of(x, y, width, height);
+    let r = TinRect::from_dimensions(center_x, center_y, width, height);
     add_draw_call(DrawCall::Rect(r))
 }
 
+/**
+ Which point of a rectangle the x, y given to `rect` place. `TinShapeMode::Corner` places the corner
+ nearest the origin: the top left with `TinCoordinateSpace::TopLeftPixels`, and the bottom left otherwise.
+*/
+pub fn rect_mode(mode: TinShapeMode) {
+    get_tin_mut().rect_mode = mode;
+}
+
 /// Draw a rectangle with rounded corners, specified by radius_x, radius_y
 pub fn rounded_rect(rect: &TinRect, radius_x: Double, radius_y: Double) {
     let rounded_rect = TinRoundedRect::new(rect.clone(), radius_x, radius_y);
@@ -187,6 +220,11 @@ where
     }))
 }
 
+/// Start a new contour in the current path. Contours inside others are cut out as holes.
+pub fn path_contour() {
+    add_draw_call(DrawCall::PathContour)
+}
+
 /// Stroke/Fill the current path.
 pub fn path_end() {
     add_draw_call(DrawCall::PathEnd)
@@ -219,12 +257,26 @@ pub fn scale(amount: Double) {
     add_draw_call(DrawCall::Scale(amount))
 }
 
+/**
+ Multiply the alpha of everything drawn after this by `amount`, from 0.0 (invisible) to 1.0.
+ A background drawn while faded is painted over what is already drawn instead of clearing it.
+ Images are not faded.
+*/
+pub fn set_opacity(amount: Double) {
+    add_draw_call(DrawCall::Opacity(amount))
+}
+
+/// Go back to the colors, line width and transform a frame starts with, for what is drawn after this.
+pub(crate) fn reset_state() {
+    add_draw_call(DrawCall::ResetState)
+}
+
 // MARK: - Image
 #[cfg(feature = "image")]
-use crate::image::TinImage;
+use crate::image::TinImageHandle;
 /// TODO: Document this function.
 #[cfg(feature = "image")]
-pub fn image(image: &'static TinImage, x: Double, y: Double) {
+pub fn image(image: &TinImageHandle, x: Double, y: Double) {
     image_with_size(
         image,
         x,
@@ -237,7 +289,7 @@ pub fn image(image: &'static TinImage, x: Double, y: Double) {
 /// TODO: Document this function.
 #[cfg(feature = "image")]
 pub fn image_with_size(
-    image: &'static TinImage,
+    image: &TinImageHandle,
     x: Double,
     y: Double,
     width: Double,
@@ -249,7 +301,7 @@ pub fn image_with_size(
 /// TODO: Document this function.
 #[cfg(feature = "image")]
 pub fn image_with_size_and_resize(
-    image: &'static TinImage,
+    image: &TinImageHandle,
     x: Double,
     y: Double,
     width: Double,
@@ -258,7 +310,7 @@ pub fn image_with_size_and_resize(
 ) {
     let center = TinPoint::from_coords(x, y);
     add_draw_call(DrawCall::Image(ImageCall {
-        image,
+        image: image.clone(),
         center,
         width,
         height,
@@ -278,12 +330,26 @@ pub fn text(message: &String, font: &crate::text::TinFont, x: Double, y: Double)
     }))
 }
 
+/// Draw text with the built-in bitmap font, with its top left corner at x, y. Needs no fonts installed.
+pub fn debug_text(x: Double, y: Double, message: &str) {
+    debug_text_with_scale(x, y, message, 1.0)
+}
+
+/// Draw text with the built-in bitmap font, with each font pixel `scale` units wide.
+pub fn debug_text_with_scale(x: Double, y: Double, message: &str, scale: Double) {
+    add_draw_call(DrawCall::DebugText(DebugTextCall {
+        message: String::from(message),
+        origin: TinPoint::from_coords(x, y),
+        scale,
+    }))
+}
+
 pub fn get_frame_count() -> crate::ULong {
     get_tin().get_frame_count()
 }
 
 fn add_draw_call(call: DrawCall) {
-    crate::context::get_tin_mut().calls.push_back(call)
+    crate::context::get_tin_mut().current_calls().push_back(call)
 }
 
 #[derive(Debug)]
@@ -293,10 +359,17 @@ pub(crate) struct PathAddCurveCall {
     pub control2: (Double, Double),
 }
 
+#[derive(Debug)]
+pub(crate) struct DebugTextCall {
+    pub message: String,
+    pub origin: TinPoint,
+    pub scale: Double,
+}
+
 #[cfg(feature = "image")]
 #[derive(Debug)]
 pub(crate) struct ImageCall {
-    pub image: &'static crate::image::TinImage,
+    pub image: crate::image::TinImageHandle,
     pub center: TinPoint,
     pub width: Double,
     pub height: Double,
@@ -306,8 +379,8 @@ pub(crate) struct ImageCall {
 #[cfg(feature = "text")]
 #[derive(Debug)]
 pub(crate) struct TextCall {
-    message: String,
-    font: crate::text::TinFont,
+    pub message: String,
+    pub font: crate::text::TinFont,
     pub center: TinPoint,
 }
 
@@ -331,8 +404,11 @@ pub(crate) enum DrawCall {
     PathBegin,
     PathVertex(TinPoint),
     PathAddCurve(PathAddCurveCall),
+    PathContour,
     PathEnd,
 
+    DebugText(DebugTextCall),
+
     FillEnable,
     FillDisable,
     StrokeEnable,
@@ -340,10 +416,16 @@ pub(crate) enum DrawCall {
 
     PushState,
     PopState,
+    ResetState,
+    Opacity(Double),
 
     Translate(Double, Double),
     Rotate(Double),
     Scale(Double),
+    /// A camera's world to screen transform to draw through, or `None` to stop.
+    Camera(Option<crate::context::DrawState>),
+    /// A viewport to draw inside, or `None` to go back to the whole window.
+    Viewport(Option<crate::viewport::TinViewport>),
 
     #[cfg(feature = "image")]
     Image(ImageCall),
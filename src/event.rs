@@ -1,19 +1,75 @@
-use crate::{key::TinKey, point::TinPoint};
+use std::path::PathBuf;
 
+use crate::{
+    key::{TinKeyEvent, TinModifiers},
+    point::TinPoint,
+    Double, Int, UInt, UShort,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "recording", derive(serde::Serialize, serde::Deserialize))]
+pub enum TinMouseButton {
+    Left,
+    Right,
+    Middle,
+    /// Any other button, numbered by the backend.
+    Other(UShort),
+}
+
+/// A mouse button press or release, with where it happened and which modifier keys were held.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "recording", derive(serde::Serialize, serde::Deserialize))]
+pub struct TinMouseEvent {
+    pub button: TinMouseButton,
+    /// In drawing coordinates, in the coordinate space the scene draws in.
+    pub position: TinPoint,
+    pub modifiers: TinModifiers,
+}
+
+/// How far a scroll wheel or trackpad moved.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "recording", derive(serde::Serialize, serde::Deserialize))]
+pub enum TinScrollDelta {
+    /// Lines or wheel notches, as reported by mouse wheels.
+    Lines(Double, Double),
+    /// Pixels, as reported by trackpads.
+    Pixels(Double, Double),
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "recording", derive(serde::Serialize, serde::Deserialize))]
 pub enum TinEvent {
-    KeyDown(TinKey),
-    KeyUp(TinKey),
+    KeyDown(TinKeyEvent),
+    KeyUp(TinKeyEvent),
+    /// A character typed, after the keyboard layout and modifier keys have been applied.
+    CharacterInput(char),
 
     MouseMoved(TinPoint),
+    MouseDown(TinMouseEvent),
+    MouseUp(TinMouseEvent),
+    MouseWheel(TinScrollDelta),
+    MouseEntered,
+    MouseExited,
 
-    MouseDown,
-    MouseUp,
+    /// The window's new size, in points.
+    WindowResized(UInt, UInt),
+    /// The window's new position on screen, in pixels.
+    WindowMoved(Int, Int),
+    /// How many pixels each point covers changed, such as when the window moved to another screen.
+    WindowPixelDensityChanged(Double),
+    WindowFocused,
+    WindowUnfocused,
+    /// The user asked to close the window.
+    WindowCloseRequested,
 
-    RightMouseDown,
-    RightMouseUp,
+    FileHovered(PathBuf),
+    FileHoverCancelled,
+    FileDropped(PathBuf),
 
-    OtherMouseDown,
-    OtherMouseUp,
+    /// A named action became active, from any of the keys or mouse buttons bound to it.
+    ActionPressed(String),
+    /// A named action stopped being active, once all of its bound keys and mouse buttons are released.
+    ActionReleased(String),
 
     Unknown,
 }
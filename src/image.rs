@@ -1,13 +1,23 @@
 use image::{DynamicImage, GenericImageView, ImageError};
 
-use crate::{
-    color::TColor,
-    draw::{image, image_with_size},
-    point::TinPoint,
-    Double, UInt,
-};
-
-#[derive(Debug)]
+use crate::{color::TColor, point::TinPoint, Double, UInt, ULong};
+
+mod composite;
+pub use composite::*;
+
+mod store;
+pub use store::*;
+
+mod sprite;
+pub use sprite::*;
+
+mod animation;
+pub use animation::*;
+
+mod render;
+pub use render::*;
+
+#[derive(Debug, Clone)]
 pub struct TinImage {
     pub(crate) image: DynamicImage,
 }
@@ -22,6 +32,11 @@ impl TinImage {
         Ok(Self::from_image(image))
     }
 
+    /// Writes the image to disk, choosing the format from the file extension.
+    pub fn save_to_file_path(&self, file_path: String) -> Result<(), ImageError> {
+        self.image.save(file_path)
+    }
+
     pub fn color(&self, at_x: UInt, at_y: UInt) -> impl TColor {
         let p = self.image.get_pixel(at_x, at_y);
         return UInt::from_rgba(
@@ -64,18 +79,15 @@ impl TinImage {
     pub fn get_height(&self) -> UInt {
         self.image.height()
     }
-
-    pub fn draw(&'static self, x: Double, y: Double) {
-        image(self, x, y);
-    }
-
-    pub fn draw_with_size(&'static self, x: Double, y: Double, width: Double, height: Double) {
-        image_with_size(self, x, y, width, height);
-    }
 }
 
 pub(crate) trait ImageRenderer {
-    fn image(&mut self, image: &TinImage, center: TinPoint, state: crate::context::DrawState) {
+    fn image(
+        &mut self,
+        image: &TinImageHandle,
+        center: TinPoint,
+        state: crate::context::DrawState,
+    ) {
         self.image_with_size(
             image,
             center,
@@ -87,7 +99,7 @@ pub(crate) trait ImageRenderer {
 
     fn image_with_size(
         &mut self,
-        image: &TinImage,
+        image: &TinImageHandle,
         center: TinPoint,
         width: Double,
         height: Double,
@@ -98,11 +110,14 @@ pub(crate) trait ImageRenderer {
 
     fn image_with_size_and_resize(
         &mut self,
-        image: &TinImage,
+        image: &TinImageHandle,
         center: TinPoint,
         width: Double,
         height: Double,
         resize: bool,
         state: crate::context::DrawState,
     );
+
+    /// Release any resources cached for an image that was removed from the store.
+    fn forget_image(&mut self, _image_id: ULong) {}
 }
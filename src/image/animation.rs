@@ -0,0 +1,187 @@
+use std::{fs::File, io::BufReader, path::Path};
+
+use image::{codecs::gif::GifDecoder, AnimationDecoder, DynamicImage, ImageError};
+
+use crate::{
+    image::{
+        add_image, frame_index_at, remove_image, TinAnimatedSprite, TinImage, TinImageHandle,
+        TinPlaybackMode,
+    },
+    Double, ULong,
+};
+
+/**
+ A sequence of images with a delay for each, loaded from an animated GIF or a numbered image sequence.
+ Frames can be sampled by time or by frame count.
+*/
+#[derive(Debug, Clone)]
+pub struct TinAnimatedImage {
+    frames: Vec<TinImage>,
+    delays: Vec<Double>,
+    /// The frames in the image store, registered by the first `to_sprite`.
+    handles: Vec<TinImageHandle>,
+}
+
+/// GIF delays at or below this many seconds are shown for `DEFAULT_GIF_DELAY` instead, as browsers do.
+pub const MIN_GIF_DELAY: Double = 0.01;
+
+/// What a GIF frame with a missing or near-zero delay is shown for, in seconds.
+pub const DEFAULT_GIF_DELAY: Double = 0.1;
+
+/// Many GIFs store a delay of 0, which would leave the animation stuck on its first frame.
+pub(crate) fn clamped_gif_delay(seconds: Double) -> Double {
+    if seconds <= MIN_GIF_DELAY {
+        DEFAULT_GIF_DELAY
+    } else {
+        seconds
+    }
+}
+
+fn not_found(message: String) -> ImageError {
+    ImageError::IoError(std::io::Error::new(std::io::ErrorKind::NotFound, message))
+}
+
+impl TinAnimatedImage {
+    /// Builds an animation from frames and their delays, in seconds.
+    pub fn from_frames(frames: Vec<(TinImage, Double)>) -> Self {
+        let (frames, delays) = frames.into_iter().unzip();
+        Self {
+            frames,
+            delays,
+            handles: Vec::new(),
+        }
+    }
+
+    /// Loads every frame of an animated GIF, keeping the delays stored in the file. Delays of 10 ms or less become 100 ms.
+    pub fn from_gif_path(file_path: String) -> Result<Self, ImageError> {
+        let file = File::open(&file_path).map_err(ImageError::IoError)?;
+        let decoder = GifDecoder::new(BufReader::new(file))?;
+        let frames = decoder
+            .into_frames()
+            .collect_frames()?
+            .into_iter()
+            .map(|frame| {
+                let (numerator, denominator) = frame.delay().numer_denom_ms();
+                let delay = numerator as Double / denominator.max(1) as Double / 1000.0;
+                let delay = clamped_gif_delay(delay);
+                let image = DynamicImage::ImageRgba8(frame.into_buffer());
+                (TinImage::from_image(image), delay)
+            })
+            .collect();
+        Ok(Self::from_frames(frames))
+    }
+
+    /**
+     Loads a numbered image sequence, such as "scans/frame_####.png".
+     The run of '#' characters is replaced with the zero-padded frame number.
+     Numbering starts at 0 or 1, and loading stops at the first missing file.
+
+     - Parameter pattern: (String) Path with a run of '#' where the frame number goes.
+     - Parameter delay: (Double) Time each frame is shown, in seconds.
+    */
+    pub fn from_sequence(pattern: String, delay: Double) -> Result<Self, ImageError> {
+        let start = pattern
+            .find('#')
+            .ok_or_else(|| not_found(format!("No '#' frame number in \"{}\"", pattern)))?;
+        let width = pattern[start..].chars().take_while(|c| *c == '#').count();
+        let (prefix, suffix) = (&pattern[..start], &pattern[start + width..]);
+        let path_for =
+            |index: ULong| format!("{}{:0width$}{}", prefix, index, suffix, width = width);
+
+        let mut index: ULong = if Path::new(&path_for(0)).exists() { 0 } else { 1 };
+        let mut frames = Vec::new();
+        while Path::new(&path_for(index)).exists() {
+            frames.push((TinImage::from_file_path(path_for(index))?, delay));
+            index += 1;
+        }
+        if frames.is_empty() {
+            return Err(not_found(format!("No frames found for \"{}\"", pattern)));
+        }
+        Ok(Self::from_frames(frames))
+    }
+
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    pub fn get_frames(&self) -> &Vec<TinImage> {
+        &self.frames
+    }
+
+    /// Delay of a frame in seconds, if the index is in range.
+    pub fn get_delay(&self, index: usize) -> Option<Double> {
+        self.delays.get(index).copied()
+    }
+
+    /// Sum of every frame delay, in seconds.
+    pub fn get_duration(&self) -> Double {
+        self.delays.iter().sum()
+    }
+
+    /// The frame shown a number of seconds after the animation started.
+    pub fn frame_at_time(&self, seconds: Double, mode: TinPlaybackMode) -> Option<&TinImage> {
+        self.frames.get(frame_index_at(&self.delays, seconds, mode))
+    }
+
+    /// The frame for a frame count, such as `draw::get_frame_count()`, advancing one image per frame.
+    pub fn frame_at_count(&self, frame_count: ULong) -> Option<&TinImage> {
+        if self.frames.is_empty() {
+            return None;
+        }
+        self.frames.get((frame_count % self.frames.len() as ULong) as usize)
+    }
+
+    /**
+     Returns a drawable sprite that keeps these delays. The frames are registered with the image store
+     the first time, and every later sprite shares them until `unload` is called.
+    */
+    pub fn to_sprite(&mut self, mode: TinPlaybackMode) -> TinAnimatedSprite {
+        if self.handles.is_empty() {
+            self.handles = self.frames.iter().map(|image| add_image(image.clone())).collect();
+        }
+        let frames = self
+            .handles
+            .iter()
+            .cloned()
+            .zip(self.delays.iter().copied())
+            .collect();
+        TinAnimatedSprite::new(frames, mode)
+    }
+
+    /// Removes the frames `to_sprite` registered from the image store. Sprites made from them stop drawing.
+    pub fn unload(&mut self) {
+        for handle in self.handles.drain(..) {
+            remove_image(&handle);
+        }
+    }
+}
+
+impl TinImage {
+    /**
+     Loads an animation from disk.
+     GIF files keep all their frames, paths containing '#' are read as a numbered sequence
+     (see `TinAnimatedImage::from_sequence`), and any other image becomes a single frame.
+
+     - Parameter frame_delay: (Double) Seconds per frame for sequences and still images.
+    */
+    pub fn animated_from_file_path(
+        file_path: String,
+        frame_delay: Double,
+    ) -> Result<TinAnimatedImage, ImageError> {
+        let is_gif = Path::new(&file_path)
+            .extension()
+            .map_or(false, |e| e.eq_ignore_ascii_case("gif"));
+        if is_gif {
+            TinAnimatedImage::from_gif_path(file_path)
+        } else if file_path.contains('#') {
+            TinAnimatedImage::from_sequence(file_path, frame_delay)
+        } else {
+            let image = TinImage::from_file_path(file_path)?;
+            Ok(TinAnimatedImage::from_frames(vec![(image, frame_delay)]))
+        }
+    }
+}
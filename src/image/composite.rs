@@ -0,0 +1,229 @@
+use image::{
+    error::{ParameterError, ParameterErrorKind},
+    DynamicImage, GenericImage, GenericImageView, GrayImage, ImageError, Luma, Rgba, RgbaImage,
+};
+
+use crate::{calculation::constrain, image::TinImage, Double, Int, UInt};
+
+/// Determines how the colors of a source image are combined with the colors beneath it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TinBlendMode {
+    Normal,
+    Multiply,
+    Screen,
+    Overlay,
+    Add,
+    Subtract,
+    Darken,
+    Lighten,
+    Difference,
+}
+
+impl Default for TinBlendMode {
+    fn default() -> Self {
+        TinBlendMode::Normal
+    }
+}
+
+impl TinBlendMode {
+    /**
+     Blends a single color channel.
+
+     - Parameter backdrop: (Double) Channel value already in the destination, from 0.0 to 1.0.
+     - Parameter source: (Double) Channel value being blended on top, from 0.0 to 1.0.
+
+     - Returns: (Double) Blended channel value, from 0.0 to 1.0.
+    */
+    pub fn blend_channel(&self, backdrop: Double, source: Double) -> Double {
+        let result = match self {
+            TinBlendMode::Normal => source,
+            TinBlendMode::Multiply => backdrop * source,
+            TinBlendMode::Screen => backdrop + source - backdrop * source,
+            TinBlendMode::Overlay => {
+                if backdrop <= 0.5 {
+                    2.0 * backdrop * source
+                } else {
+                    1.0 - 2.0 * (1.0 - backdrop) * (1.0 - source)
+                }
+            }
+            TinBlendMode::Add => backdrop + source,
+            TinBlendMode::Subtract => backdrop - source,
+            TinBlendMode::Darken => backdrop.min(source),
+            TinBlendMode::Lighten => backdrop.max(source),
+            TinBlendMode::Difference => (backdrop - source).abs(),
+        };
+        constrain(result, 0.0, 1.0)
+    }
+
+    /**
+     Composites a source pixel over a backdrop pixel.
+     Channels are RGBA values from 0.0 to 1.0, and coverage scales the source alpha
+     (the product of opacity and mask).
+    */
+    pub(crate) fn composite(
+        &self,
+        backdrop: [Double; 4],
+        source: [Double; 4],
+        coverage: Double,
+    ) -> [Double; 4] {
+        let source_alpha = constrain(source[3] * coverage, 0.0, 1.0);
+        let backdrop_alpha = backdrop[3];
+        let out_alpha = source_alpha + backdrop_alpha * (1.0 - source_alpha);
+        if out_alpha <= 0.0 {
+            return [0.0, 0.0, 0.0, 0.0];
+        }
+
+        let mut out = [0.0, 0.0, 0.0, out_alpha];
+        for i in 0..3 {
+            // Where the backdrop is transparent the source color shows through unblended.
+            let blended = (1.0 - backdrop_alpha) * source[i]
+                + backdrop_alpha * self.blend_channel(backdrop[i], source[i]);
+            out[i] = (source_alpha * blended + backdrop_alpha * backdrop[i] * (1.0 - source_alpha))
+                / out_alpha;
+        }
+        out
+    }
+}
+
+fn to_unit(pixel: Rgba<u8>) -> [Double; 4] {
+    [
+        pixel.0[0] as Double / 255.0,
+        pixel.0[1] as Double / 255.0,
+        pixel.0[2] as Double / 255.0,
+        pixel.0[3] as Double / 255.0,
+    ]
+}
+
+fn from_unit(values: [Double; 4]) -> Rgba<u8> {
+    let mut bytes = [0_u8; 4];
+    for i in 0..4 {
+        bytes[i] = (constrain(values[i], 0.0, 1.0) * 255.0).round() as u8;
+    }
+    Rgba(bytes)
+}
+
+/// Mask strength at a pixel: its luminance multiplied by its alpha.
+fn mask_value(pixel: Rgba<u8>) -> Double {
+    let p = to_unit(pixel);
+    (0.2126 * p[0] + 0.7152 * p[1] + 0.0722 * p[2]) * p[3]
+}
+
+fn dimension_mismatch() -> ImageError {
+    ImageError::Parameter(ParameterError::from_kind(
+        ParameterErrorKind::DimensionMismatch,
+    ))
+}
+
+impl TinImage {
+    /**
+     Blends another image onto this one.
+
+     - Parameter source: (&TinImage) Image to draw on top of this one.
+     - Parameter x: (Int) Horizontal offset of the source's left edge, in pixels.
+     - Parameter y: (Int) Vertical offset of the source's top edge, in pixels.
+     - Parameter opacity: (Double) Overall opacity of the source, from 0.0 to 1.0.
+     - Parameter mode: (TinBlendMode) How source colors combine with this image.
+     - Parameter mask: (Option<&TinImage>) Optional alpha mask, aligned with the source.
+       Its luminance times its alpha scales the source coverage.
+
+     Source pixels falling outside this image, or outside the mask, are ignored.
+    */
+    pub fn blend(
+        &mut self,
+        source: &TinImage,
+        x: Int,
+        y: Int,
+        opacity: Double,
+        mode: TinBlendMode,
+        mask: Option<&TinImage>,
+    ) {
+        let opacity = constrain(opacity, 0.0, 1.0);
+        let (dest_width, dest_height) = (self.get_width() as Int, self.get_height() as Int);
+        let (mut source_width, mut source_height) = (source.get_width(), source.get_height());
+        if let Some(m) = mask {
+            source_width = source_width.min(m.get_width());
+            source_height = source_height.min(m.get_height());
+        }
+
+        for sy in 0..source_height {
+            let dy = y + sy as Int;
+            if dy < 0 || dy >= dest_height {
+                continue;
+            }
+            for sx in 0..source_width {
+                let dx = x + sx as Int;
+                if dx < 0 || dx >= dest_width {
+                    continue;
+                }
+                let coverage = match mask {
+                    Some(m) => opacity * mask_value(m.image.get_pixel(sx, sy)),
+                    None => opacity,
+                };
+                let backdrop = to_unit(self.image.get_pixel(dx as UInt, dy as UInt));
+                let top = to_unit(source.image.get_pixel(sx, sy));
+                let result = mode.composite(backdrop, top, coverage);
+                self.image.put_pixel(dx as UInt, dy as UInt, from_unit(result));
+            }
+        }
+    }
+
+    /// Returns a copy of this image with another image blended onto it. See `TinImage::blend`.
+    pub fn blended(
+        &self,
+        source: &TinImage,
+        x: Int,
+        y: Int,
+        opacity: Double,
+        mode: TinBlendMode,
+        mask: Option<&TinImage>,
+    ) -> TinImage {
+        let mut result = self.clone();
+        result.blend(source, x, y, opacity, mode, mask);
+        result
+    }
+
+    /// Splits the image into four grayscale images, holding the red, green, blue and alpha channels.
+    pub fn split_channels(&self) -> [TinImage; 4] {
+        let rgba = self.image.to_rgba8();
+        let (width, height) = rgba.dimensions();
+        let channel = |index: usize| {
+            let gray = GrayImage::from_fn(width, height, |x, y| Luma([rgba.get_pixel(x, y).0[index]]));
+            TinImage::from_image(DynamicImage::ImageLuma8(gray))
+        };
+        [channel(0), channel(1), channel(2), channel(3)]
+    }
+
+    /**
+     Recombines four grayscale images into a single RGBA image.
+     Colored inputs are converted to grayscale first. Returns an error unless all four images share dimensions.
+    */
+    pub fn merge_channels(
+        red: &TinImage,
+        green: &TinImage,
+        blue: &TinImage,
+        alpha: &TinImage,
+    ) -> Result<Self, ImageError> {
+        let (width, height) = (red.get_width(), red.get_height());
+        for channel in [green, blue, alpha] {
+            if channel.get_width() != width || channel.get_height() != height {
+                return Err(dimension_mismatch());
+            }
+        }
+
+        let channels = [
+            red.image.to_luma8(),
+            green.image.to_luma8(),
+            blue.image.to_luma8(),
+            alpha.image.to_luma8(),
+        ];
+        let merged = RgbaImage::from_fn(width, height, |x, y| {
+            Rgba([
+                channels[0].get_pixel(x, y).0[0],
+                channels[1].get_pixel(x, y).0[0],
+                channels[2].get_pixel(x, y).0[0],
+                channels[3].get_pixel(x, y).0[0],
+            ])
+        });
+        Ok(Self::from_image(DynamicImage::ImageRgba8(merged)))
+    }
+}
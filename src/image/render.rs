@@ -0,0 +1,34 @@
+use crate::{
+    backends::software::SoftwareBackend,
+    context::{get_tin_mut, TinContext},
+    frame::TinFrame,
+    image::TinImage,
+    UInt,
+};
+
+/**
+ Renders draw calls into a new image instead of the screen.
+ Every draw function called inside `draw` is captured and rasterized on the CPU into a
+ transparent canvas of the given size, using centered coordinates with y pointing up.
+ Register the result with `add_image` to draw it, or save it with `TinImage::save_to_file_path`.
+
+ ```ignore
+ let background = render_to_image(512, 512, || {
+     background(0.1, 0.1, 0.2);
+     ellipse(0.0, 0.0, 200.0, 200.0);
+ });
+ let handle = add_image(background);
+ ```
+*/
+pub fn render_to_image(width: UInt, height: UInt, draw: impl FnOnce()) -> TinImage {
+    // Set aside calls already queued for the screen, so only the closure's calls are captured.
+    let queued = std::mem::take(get_tin_mut().current_calls());
+    draw();
+    let captured = std::mem::replace(get_tin_mut().current_calls(), queued);
+
+    let mut offscreen = TinContext::<SoftwareBackend>::new();
+    offscreen.prepare(TinFrame::new(width, height));
+    *offscreen.current_calls() = captured;
+    offscreen.process_draw_calls();
+    offscreen.render.to_image()
+}
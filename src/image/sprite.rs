@@ -0,0 +1,360 @@
+use std::collections::HashMap;
+
+use crate::{
+    image::{add_image, remove_image, TinImage, TinImageHandle},
+    Double, UInt,
+};
+
+/// Controls what an animation does once it reaches its last frame.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TinPlaybackMode {
+    /// Start over from the first frame.
+    Loop,
+    /// Play backwards to the first frame, then forwards again.
+    PingPong,
+    /// Stop on the last frame.
+    Once,
+}
+
+/**
+ Finds the frame shown at a point in time.
+
+ - Parameter durations: (&[Double]) Duration of each frame, in seconds.
+ - Parameter time: (Double) Time since the animation started, in seconds.
+ - Parameter mode: (TinPlaybackMode) What to do after the last frame.
+
+ - Returns: (usize) Index of the frame to show.
+*/
+pub(crate) fn frame_index_at(durations: &[Double], time: Double, mode: TinPlaybackMode) -> usize {
+    let count = durations.len();
+    if count <= 1 {
+        return 0;
+    }
+
+    // In ping-pong, the sequence is played forwards, then backwards without repeating the end frames.
+    let sequence: Vec<usize> = match mode {
+        TinPlaybackMode::PingPong => (0..count).chain((1..count - 1).rev()).collect(),
+        _ => (0..count).collect(),
+    };
+    let total: Double = sequence.iter().map(|i| durations[*i].max(0.0)).sum();
+    if total <= 0.0 {
+        return 0;
+    }
+
+    let mut t = time.max(0.0);
+    match mode {
+        TinPlaybackMode::Once if t >= total => return count - 1,
+        TinPlaybackMode::Once => {}
+        _ => t %= total,
+    }
+    for index in sequence {
+        let d = durations[index].max(0.0);
+        if t < d {
+            return index;
+        }
+        t -= d;
+    }
+    count - 1
+}
+
+/// Computes the (x, y, width, height) of every whole cell in a grid, row by row.
+pub(crate) fn grid_cells(
+    image_width: UInt,
+    image_height: UInt,
+    frame_width: UInt,
+    frame_height: UInt,
+    spacing: UInt,
+) -> Vec<(UInt, UInt, UInt, UInt)> {
+    let mut cells = Vec::new();
+    if frame_width == 0 || frame_height == 0 {
+        return cells;
+    }
+    let mut y = 0;
+    while y + frame_height <= image_height {
+        let mut x = 0;
+        while x + frame_width <= image_width {
+            cells.push((x, y, frame_width, frame_height));
+            x += frame_width + spacing;
+        }
+        y += frame_height + spacing;
+    }
+    cells
+}
+
+#[derive(Debug, Clone)]
+struct TinSpriteFrame {
+    name: String,
+    handle: TinImageHandle,
+    duration: Option<Double>,
+}
+
+/**
+ A set of named frames sliced out of a single `TinImage`.
+ Each frame is registered with the image store, so it can be drawn like any other image handle.
+*/
+#[derive(Debug, Clone, Default)]
+pub struct TinSpriteSheet {
+    frames: Vec<TinSpriteFrame>,
+    names: HashMap<String, usize>,
+}
+
+impl TinSpriteSheet {
+    /**
+     Slices an image into a grid of equally sized frames, read left to right, top to bottom.
+     Frames are named by their index ("0", "1", ...). Partial cells at the edges are skipped.
+    */
+    pub fn from_grid(
+        image: &TinImage,
+        frame_width: UInt,
+        frame_height: UInt,
+        spacing: UInt,
+    ) -> Self {
+        let mut sheet = Self::default();
+        let cells = grid_cells(
+            image.get_width(),
+            image.get_height(),
+            frame_width,
+            frame_height,
+            spacing,
+        );
+        for (index, cell) in cells.into_iter().enumerate() {
+            sheet.push_frame(index.to_string(), image, cell, None);
+        }
+        sheet
+    }
+
+    /// Crops the (x, y, width, height) cell out of the image and registers it as the next frame.
+    fn push_frame(
+        &mut self,
+        name: String,
+        image: &TinImage,
+        cell: (UInt, UInt, UInt, UInt),
+        duration: Option<Double>,
+    ) {
+        let (x, y, width, height) = cell;
+        let cropped = TinImage::from_image(image.image.crop_imm(x, y, width, height));
+        self.names.insert(name.clone(), self.frames.len());
+        self.frames.push(TinSpriteFrame {
+            name,
+            handle: add_image(cropped),
+            duration,
+        });
+    }
+
+    /**
+     Removes every frame from the image store, so backends release their textures.
+     Handles already taken from the sheet, such as in animations, still hold their images.
+     The sheet is left empty.
+    */
+    pub fn unload(&mut self) {
+        for frame in self.frames.drain(..) {
+            remove_image(&frame.handle);
+        }
+        self.names.clear();
+    }
+
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    /// Frame names, in sheet order.
+    pub fn names(&self) -> Vec<&str> {
+        self.frames.iter().map(|f| f.name.as_str()).collect()
+    }
+
+    pub fn frame(&self, name: &str) -> Option<&TinImageHandle> {
+        self.names.get(name).map(|i| &self.frames[*i].handle)
+    }
+
+    pub fn frame_at(&self, index: usize) -> Option<&TinImageHandle> {
+        self.frames.get(index).map(|f| &f.handle)
+    }
+
+    /**
+     Builds an animation from the named frames.
+     Frames with a duration from the atlas keep it; all others last `frame_duration` seconds.
+     Unknown names are skipped.
+    */
+    pub fn animation(
+        &self,
+        names: &[&str],
+        frame_duration: Double,
+        mode: TinPlaybackMode,
+    ) -> TinAnimatedSprite {
+        let frames = names
+            .iter()
+            .filter_map(|name| self.names.get(*name))
+            .map(|i| {
+                let f = &self.frames[*i];
+                (f.handle.clone(), f.duration.unwrap_or(frame_duration))
+            })
+            .collect();
+        TinAnimatedSprite::new(frames, mode)
+    }
+
+    /// Builds an animation from every frame of the sheet, in order.
+    pub fn animation_all(&self, frame_duration: Double, mode: TinPlaybackMode) -> TinAnimatedSprite {
+        let names = self.names();
+        self.animation(&names, frame_duration, mode)
+    }
+}
+
+#[cfg(feature = "atlas")]
+#[derive(Debug)]
+pub enum TinAtlasError {
+    Io(std::io::Error),
+    Json(serde_json::Error),
+    Image(image::ImageError),
+    /// The JSON was valid but did not describe frames in a known layout.
+    Format(String),
+    /// Two frames have the same name.
+    DuplicateName(String),
+}
+
+#[cfg(feature = "atlas")]
+impl TinSpriteSheet {
+    /**
+     Slices an image using a JSON atlas in the TexturePacker / Aseprite layout:
+     a "frames" object keyed by name (ordered by name), or a "frames" array whose entries have a "filename".
+     Each entry needs a "frame" object with "x", "y", "w" and "h".
+     An optional "duration" (milliseconds) is used by `animation`.
+    */
+    pub fn from_atlas_json(image: &TinImage, json: &str) -> Result<Self, TinAtlasError> {
+        use serde_json::Value;
+
+        let root: Value = serde_json::from_str(json).map_err(TinAtlasError::Json)?;
+        let entries: Vec<(String, &Value)> = match root.get("frames") {
+            Some(Value::Object(map)) => map.iter().map(|(k, v)| (k.clone(), v)).collect(),
+            Some(Value::Array(list)) => list
+                .iter()
+                .enumerate()
+                .map(|(i, v)| {
+                    let name = v
+                        .get("filename")
+                        .and_then(Value::as_str)
+                        .map(String::from)
+                        .unwrap_or_else(|| i.to_string());
+                    (name, v)
+                })
+                .collect(),
+            _ => return Err(TinAtlasError::Format(String::from("missing \"frames\""))),
+        };
+
+        // Every entry is checked before any frame is registered, so a bad atlas leaves nothing in the store.
+        let mut frames = Vec::with_capacity(entries.len());
+        let mut seen = std::collections::HashSet::new();
+        for (name, entry) in entries {
+            if !seen.insert(name.clone()) {
+                return Err(TinAtlasError::DuplicateName(name));
+            }
+            let rect = entry
+                .get("frame")
+                .ok_or_else(|| TinAtlasError::Format(format!("frame \"{}\" has no rect", name)))?;
+            let field = |key: &str| {
+                rect.get(key).and_then(Value::as_u64).ok_or_else(|| {
+                    TinAtlasError::Format(format!("frame \"{}\" is missing \"{}\"", name, key))
+                })
+            };
+            let (x, y, w, h) = (field("x")?, field("y")?, field("w")?, field("h")?);
+            let duration = entry
+                .get("duration")
+                .and_then(Value::as_f64)
+                .map(|ms| ms / 1000.0);
+            frames.push((name, (x as UInt, y as UInt, w as UInt, h as UInt), duration));
+        }
+
+        let mut sheet = Self::default();
+        for (name, cell, duration) in frames {
+            sheet.push_frame(name, image, cell, duration);
+        }
+        Ok(sheet)
+    }
+
+    /// Loads an image and its JSON atlas from disk. See `from_atlas_json`.
+    pub fn from_atlas_files(image_path: String, json_path: String) -> Result<Self, TinAtlasError> {
+        let image = TinImage::from_file_path(image_path).map_err(TinAtlasError::Image)?;
+        let json = std::fs::read_to_string(json_path).map_err(TinAtlasError::Io)?;
+        Self::from_atlas_json(&image, &json)
+    }
+}
+
+/**
+ A sequence of image frames with per-frame durations.
+ Advance it with the time since the last update, then draw it like an image.
+*/
+#[derive(Debug, Clone)]
+pub struct TinAnimatedSprite {
+    frames: Vec<(TinImageHandle, Double)>,
+    durations: Vec<Double>,
+    pub mode: TinPlaybackMode,
+    elapsed: Double,
+    playing: bool,
+}
+
+impl TinAnimatedSprite {
+    /// Frame durations are in seconds.
+    pub fn new(frames: Vec<(TinImageHandle, Double)>, mode: TinPlaybackMode) -> Self {
+        let durations = frames.iter().map(|(_, d)| *d).collect();
+        Self {
+            frames,
+            durations,
+            mode,
+            elapsed: 0.0,
+            playing: true,
+        }
+    }
+
+    /// Advance the animation by a number of seconds, unless it is paused.
+    pub fn advance(&mut self, seconds: Double) {
+        if self.playing {
+            self.elapsed += seconds;
+        }
+    }
+
+    pub fn play(&mut self) {
+        self.playing = true;
+    }
+
+    pub fn pause(&mut self) {
+        self.playing = false;
+    }
+
+    pub fn is_playing(&self) -> bool {
+        self.playing
+    }
+
+    /// Go back to the first frame.
+    pub fn rewind(&mut self) {
+        self.elapsed = 0.0;
+    }
+
+    /// True once a `Once` animation has shown its last frame for its full duration.
+    pub fn is_finished(&self) -> bool {
+        self.mode == TinPlaybackMode::Once && self.elapsed >= self.durations.iter().sum::<Double>()
+    }
+
+    pub fn get_frame_index(&self) -> usize {
+        frame_index_at(&self.durations, self.elapsed, self.mode)
+    }
+
+    pub fn get_current_frame(&self) -> Option<&TinImageHandle> {
+        self.frames.get(self.get_frame_index()).map(|(h, _)| h)
+    }
+
+    /// Draw the current frame centered on x, y with the current transform.
+    pub fn draw(&self, x: Double, y: Double) {
+        if let Some(frame) = self.get_current_frame() {
+            frame.draw(x, y);
+        }
+    }
+
+    pub fn draw_with_size(&self, x: Double, y: Double, width: Double, height: Double) {
+        if let Some(frame) = self.get_current_frame() {
+            frame.draw_with_size(x, y, width, height);
+        }
+    }
+}
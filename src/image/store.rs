@@ -0,0 +1,163 @@
+use std::{collections::HashMap, ops::Deref, sync::Arc};
+
+use image::ImageError;
+
+use crate::{
+    context::get_tin_mut,
+    draw::{image, image_with_size},
+    image::TinImage,
+    Double, ULong,
+};
+
+/**
+ A cheap, reference-counted reference to a `TinImage` registered with a `TinImageStore`.
+ Cloning a handle does not copy the image. Backends use the handle's id to cache uploaded textures.
+*/
+#[derive(Debug, Clone)]
+pub struct TinImageHandle {
+    id: ULong,
+    image: Arc<TinImage>,
+}
+
+impl TinImageHandle {
+    pub fn get_id(&self) -> ULong {
+        self.id
+    }
+
+    pub fn get_image(&self) -> &TinImage {
+        &self.image
+    }
+
+    pub fn draw(&self, x: Double, y: Double) {
+        image(self, x, y);
+    }
+
+    pub fn draw_with_size(&self, x: Double, y: Double, width: Double, height: Double) {
+        image_with_size(self, x, y, width, height);
+    }
+}
+
+impl Deref for TinImageHandle {
+    type Target = TinImage;
+
+    fn deref(&self) -> &Self::Target {
+        &self.image
+    }
+}
+
+impl PartialEq for TinImageHandle {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+/**
+ Owns the images available for drawing, and hands out `TinImageHandle`s to them.
+ Removing an image only drops the store's reference; outstanding handles keep it alive,
+ but backends are told to release any texture cached for it.
+*/
+#[derive(Debug, Default)]
+pub struct TinImageStore {
+    next_id: ULong,
+    images: HashMap<ULong, TinImageHandle>,
+    evicted: Vec<ULong>,
+}
+
+impl TinImageStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers an image and returns a handle to it.
+    pub fn add(&mut self, image: TinImage) -> TinImageHandle {
+        let handle = TinImageHandle {
+            id: self.next_id,
+            image: Arc::new(image),
+        };
+        self.next_id += 1;
+        self.images.insert(handle.id, handle.clone());
+        handle
+    }
+
+    /// Returns the handle with the given id, if it has not been removed.
+    pub fn get(&self, id: ULong) -> Option<TinImageHandle> {
+        self.images.get(&id).cloned()
+    }
+
+    /// Unregisters an image. Returns false if it was not in the store.
+    pub fn remove(&mut self, handle: &TinImageHandle) -> bool {
+        match self.images.remove(&handle.id) {
+            Some(_) => {
+                self.evicted.push(handle.id);
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.images.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.images.is_empty()
+    }
+
+    /// Ids removed since the last call, so renderers can drop their cached textures.
+    pub(crate) fn drain_evicted(&mut self) -> Vec<ULong> {
+        std::mem::take(&mut self.evicted)
+    }
+}
+
+/**
+ A per-handle cache of backend resources, such as uploaded textures.
+ Entries are created on first use and dropped when the image is removed from the store.
+*/
+pub(crate) struct TinTextureCache<T> {
+    entries: HashMap<ULong, T>,
+}
+
+impl<T> Default for TinTextureCache<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> TinTextureCache<T> {
+    pub fn new() -> Self {
+        Self {
+            entries: HashMap::new(),
+        }
+    }
+
+    /// The entry for a handle, made from its image with `upload` if there isn't one yet.
+    pub fn get_or_insert_with(
+        &mut self,
+        handle: &TinImageHandle,
+        upload: impl FnOnce(&TinImage) -> T,
+    ) -> &T {
+        self.entries
+            .entry(handle.get_id())
+            .or_insert_with(|| upload(handle.get_image()))
+    }
+
+    pub fn evict(&mut self, id: ULong) {
+        self.entries.remove(&id);
+    }
+}
+
+/// Registers an image with the global store, returning a handle usable with the draw functions.
+pub fn add_image(image: TinImage) -> TinImageHandle {
+    get_tin_mut().images.add(image)
+}
+
+/// Loads an image from disk and registers it with the global store.
+pub fn load_image(file_path: String) -> Result<TinImageHandle, ImageError> {
+    let image = TinImage::from_file_path(file_path)?;
+    Ok(add_image(image))
+}
+
+/// Removes an image from the global store. Returns false if it was already removed.
+pub fn remove_image(handle: &TinImageHandle) -> bool {
+    get_tin_mut().images.remove(handle)
+}
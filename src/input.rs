@@ -0,0 +1,180 @@
+use std::collections::HashSet;
+
+use crate::{
+    context::get_tin,
+    event::{TinEvent, TinMouseButton},
+    key::{TinKey, TinModifiers},
+    point::TPoint,
+    vector2::TinVector2,
+    Double,
+};
+
+mod action;
+pub use action::*;
+
+/**
+ Which keys and mouse buttons are held, and which changed since the last frame.
+ The context feeds it every event, and clears the per-frame changes after each update.
+*/
+#[derive(Debug, Default)]
+pub(crate) struct TinInputState {
+    keys_down: HashSet<TinKey>,
+    keys_pressed: HashSet<TinKey>,
+    keys_released: HashSet<TinKey>,
+    buttons_down: HashSet<TinMouseButton>,
+    buttons_pressed: HashSet<TinMouseButton>,
+    buttons_released: HashSet<TinMouseButton>,
+    modifiers: TinModifiers,
+}
+
+impl TinInputState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn handle_event(&mut self, event: &TinEvent) {
+        match event {
+            TinEvent::KeyDown(event) => {
+                // Key repeat sends more key downs, which are not new presses.
+                if self.keys_down.insert(event.key) {
+                    self.keys_pressed.insert(event.key);
+                }
+                self.modifiers = event.modifiers;
+            }
+            TinEvent::KeyUp(event) => {
+                self.keys_down.remove(&event.key);
+                self.keys_released.insert(event.key);
+                self.modifiers = event.modifiers;
+            }
+            TinEvent::MouseDown(mouse) => {
+                if self.buttons_down.insert(mouse.button) {
+                    self.buttons_pressed.insert(mouse.button);
+                }
+                self.modifiers = mouse.modifiers;
+            }
+            TinEvent::MouseUp(mouse) => {
+                self.buttons_down.remove(&mouse.button);
+                self.buttons_released.insert(mouse.button);
+                self.modifiers = mouse.modifiers;
+            }
+            // Releases are never seen once the window loses focus, so nothing is held anymore.
+            TinEvent::WindowUnfocused => {
+                self.keys_released.extend(self.keys_down.drain());
+                self.buttons_released.extend(self.buttons_down.drain());
+                self.modifiers = TinModifiers::NONE;
+            }
+            _ => {}
+        }
+    }
+
+    /// Forget which keys and buttons changed, once the frame that could see them is over.
+    pub fn end_frame(&mut self) {
+        self.keys_pressed.clear();
+        self.keys_released.clear();
+        self.buttons_pressed.clear();
+        self.buttons_released.clear();
+    }
+
+    pub fn is_key_down(&self, key: TinKey) -> bool {
+        self.keys_down.contains(&key)
+    }
+
+    pub fn key_just_pressed(&self, key: TinKey) -> bool {
+        self.keys_pressed.contains(&key)
+    }
+
+    pub fn key_just_released(&self, key: TinKey) -> bool {
+        self.keys_released.contains(&key)
+    }
+
+    pub fn is_button_down(&self, button: TinMouseButton) -> bool {
+        self.buttons_down.contains(&button)
+    }
+
+    pub fn button_just_pressed(&self, button: TinMouseButton) -> bool {
+        self.buttons_pressed.contains(&button)
+    }
+
+    pub fn button_just_released(&self, button: TinMouseButton) -> bool {
+        self.buttons_released.contains(&button)
+    }
+
+    pub fn any_button_down(&self) -> bool {
+        !self.buttons_down.is_empty()
+    }
+
+    pub fn get_modifiers(&self) -> TinModifiers {
+        self.modifiers
+    }
+}
+
+// MARK: - Global input state
+
+/// True while a key is held.
+pub fn is_key_down(key: TinKey) -> bool {
+    get_tin().input.is_key_down(key)
+}
+
+/// True during the first frame after a key was pressed.
+pub fn key_just_pressed(key: TinKey) -> bool {
+    get_tin().input.key_just_pressed(key)
+}
+
+/// True during the first frame after a key was released.
+pub fn key_just_released(key: TinKey) -> bool {
+    get_tin().input.key_just_released(key)
+}
+
+/// The modifier keys held during the latest key or mouse button event.
+pub fn modifiers() -> TinModifiers {
+    get_tin().input.get_modifiers()
+}
+
+/// True while a mouse button is held.
+pub fn mouse_button_down(button: TinMouseButton) -> bool {
+    get_tin().input.is_button_down(button)
+}
+
+/// True during the first frame after a mouse button was pressed.
+pub fn mouse_button_just_pressed(button: TinMouseButton) -> bool {
+    get_tin().input.button_just_pressed(button)
+}
+
+/// True during the first frame after a mouse button was released.
+pub fn mouse_button_just_released(button: TinMouseButton) -> bool {
+    get_tin().input.button_just_released(button)
+}
+
+/// True while any mouse button is held.
+pub fn is_mouse_pressed() -> bool {
+    get_tin().mouse_pressed
+}
+
+/// Horizontal mouse position, in drawing coordinates.
+pub fn mouse_x() -> Double {
+    get_tin().mouse_pos.get_x()
+}
+
+/// Vertical mouse position, in drawing coordinates.
+pub fn mouse_y() -> Double {
+    get_tin().mouse_pos.get_y()
+}
+
+/// Horizontal mouse position during the previous frame.
+pub fn pmouse_x() -> Double {
+    get_tin().prev_mouse_pos.get_x()
+}
+
+/// Vertical mouse position during the previous frame.
+pub fn pmouse_y() -> Double {
+    get_tin().prev_mouse_pos.get_y()
+}
+
+/// How far the mouse moved since the previous frame, in drawing units per frame.
+pub fn mouse_velocity() -> TinVector2 {
+    let tin = get_tin();
+    TinVector2::from_xy(
+        tin.mouse_pos.get_x() - tin.prev_mouse_pos.get_x(),
+        tin.mouse_pos.get_y() - tin.prev_mouse_pos.get_y(),
+    )
+}
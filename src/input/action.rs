@@ -0,0 +1,356 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+    str::FromStr,
+};
+
+use crate::{
+    context::{get_tin, get_tin_mut},
+    event::{TinEvent, TinMouseButton},
+    key::{TinKey, TinModifiers},
+    UShort,
+};
+
+/// A key, optionally with modifier keys, or a mouse button that an action can be bound to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TinInputTrigger {
+    /// Fires when the key is pressed while at least these modifiers are held.
+    Key(TinKey, TinModifiers),
+    MouseButton(TinMouseButton),
+}
+
+impl TinInputTrigger {
+    pub fn key(key: TinKey) -> Self {
+        TinInputTrigger::Key(key, TinModifiers::NONE)
+    }
+
+    pub fn key_with_modifiers(key: TinKey, modifiers: TinModifiers) -> Self {
+        TinInputTrigger::Key(key, modifiers)
+    }
+
+    pub fn mouse_button(button: TinMouseButton) -> Self {
+        TinInputTrigger::MouseButton(button)
+    }
+}
+
+const MODIFIER_NAMES: [(&str, TinModifiers); 4] = [
+    ("Shift", TinModifiers::SHIFT),
+    ("Control", TinModifiers::CONTROL),
+    ("Alt", TinModifiers::ALT),
+    ("Logo", TinModifiers::LOGO),
+];
+
+/// Written as in binding files: "Space", "Control+Shift+S", "MouseLeft" or "Mouse4".
+impl fmt::Display for TinInputTrigger {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TinInputTrigger::Key(key, modifiers) => {
+                for (name, modifier) in MODIFIER_NAMES.iter() {
+                    if modifiers.contains(*modifier) {
+                        write!(f, "{}+", name)?;
+                    }
+                }
+                write!(f, "{}", key.name())
+            }
+            TinInputTrigger::MouseButton(TinMouseButton::Left) => write!(f, "MouseLeft"),
+            TinInputTrigger::MouseButton(TinMouseButton::Right) => write!(f, "MouseRight"),
+            TinInputTrigger::MouseButton(TinMouseButton::Middle) => write!(f, "MouseMiddle"),
+            TinInputTrigger::MouseButton(TinMouseButton::Other(number)) => write!(f, "Mouse{}", number),
+        }
+    }
+}
+
+/// Parses what `Display` writes. Names ignore case, and "Ctrl", "Super", "Cmd" and "Command" are also accepted.
+impl FromStr for TinInputTrigger {
+    type Err = String;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let parts: Vec<&str> = text.split('+').map(str::trim).collect();
+        let (last, modifier_parts) = parts.split_last().ok_or_else(|| String::from("empty trigger"))?;
+
+        let mut modifiers = TinModifiers::NONE;
+        for part in modifier_parts {
+            let modifier = match part.to_ascii_lowercase().as_str() {
+                "shift" => TinModifiers::SHIFT,
+                "control" | "ctrl" => TinModifiers::CONTROL,
+                "alt" | "option" => TinModifiers::ALT,
+                "logo" | "super" | "cmd" | "command" => TinModifiers::LOGO,
+                _ => return Err(format!("unknown modifier \"{}\"", part)),
+            };
+            modifiers.insert(modifier);
+        }
+
+        let lowercase = last.to_ascii_lowercase();
+        if let Some(button) = lowercase.strip_prefix("mouse") {
+            if !modifiers.is_empty() {
+                return Err(format!("mouse button \"{}\" can't have modifiers", last));
+            }
+            let button = match button {
+                "left" => TinMouseButton::Left,
+                "right" => TinMouseButton::Right,
+                "middle" => TinMouseButton::Middle,
+                number => TinMouseButton::Other(
+                    number
+                        .parse::<UShort>()
+                        .map_err(|_| format!("unknown mouse button \"{}\"", last))?,
+                ),
+            };
+            return Ok(TinInputTrigger::MouseButton(button));
+        }
+        let key = TinKey::from_name(last).ok_or_else(|| format!("unknown key \"{}\"", last))?;
+        Ok(TinInputTrigger::Key(key, modifiers))
+    }
+}
+
+#[derive(Debug)]
+pub enum TinBindingsError {
+    Io(std::io::Error),
+    /// A line that is not a binding, numbered from 1.
+    Parse { line: usize, message: String },
+}
+
+/**
+ Named actions and the triggers bound to each one. An action can have several triggers,
+ and is active while any of them is held.
+
+ The text form has one action per line, followed by `=` and its triggers separated by commas.
+ Lines starting with `#` are comments:
+
+ ```text
+ # Controls for the installation
+ jump = Space, W
+ save = Control+S
+ next_palette = MouseRight, Tab
+ ```
+*/
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TinActionBindings {
+    bindings: Vec<(String, Vec<TinInputTrigger>)>,
+}
+
+impl TinActionBindings {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a trigger to an action, creating the action if needed.
+    pub fn bind(&mut self, action: &str, trigger: TinInputTrigger) {
+        match self.bindings.iter_mut().find(|(name, _)| name == action) {
+            Some((_, triggers)) => {
+                if !triggers.contains(&trigger) {
+                    triggers.push(trigger);
+                }
+            }
+            None => self.bindings.push((String::from(action), vec![trigger])),
+        }
+    }
+
+    /// Remove an action and all of its triggers.
+    pub fn unbind(&mut self, action: &str) {
+        self.bindings.retain(|(name, _)| name != action);
+    }
+
+    /// The triggers bound to an action, empty for an unknown action.
+    pub fn get_triggers(&self, action: &str) -> &[TinInputTrigger] {
+        self.bindings
+            .iter()
+            .find(|(name, _)| name == action)
+            .map_or(&[][..], |(_, triggers)| triggers.as_slice())
+    }
+
+    /// Every action name, in the order they were first bound.
+    pub fn action_names(&self) -> Vec<String> {
+        self.bindings.iter().map(|(name, _)| name.clone()).collect()
+    }
+
+    /// Bindings from their text form. See the type's documentation for the format.
+    pub fn from_config(config: &str) -> Result<Self, TinBindingsError> {
+        let mut bindings = Self::new();
+        for (index, line) in config.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let parse_error = |message: String| TinBindingsError::Parse {
+                line: index + 1,
+                message,
+            };
+            let (action, triggers) = line
+                .split_once('=')
+                .ok_or_else(|| parse_error(String::from("expected \"action = triggers\"")))?;
+            let action = action.trim();
+            if action.is_empty() {
+                return Err(parse_error(String::from("missing action name")));
+            }
+            for trigger in triggers.split(',').map(str::trim).filter(|t| !t.is_empty()) {
+                bindings.bind(action, trigger.parse().map_err(parse_error)?);
+            }
+        }
+        Ok(bindings)
+    }
+
+    /// The bindings in their text form, one action per line.
+    pub fn to_config(&self) -> String {
+        self.bindings
+            .iter()
+            .map(|(name, triggers)| {
+                let triggers: Vec<String> = triggers.iter().map(|t| t.to_string()).collect();
+                format!("{} = {}\n", name, triggers.join(", "))
+            })
+            .collect()
+    }
+
+    pub fn from_file_path(file_path: &str) -> Result<Self, TinBindingsError> {
+        let config = std::fs::read_to_string(file_path).map_err(TinBindingsError::Io)?;
+        Self::from_config(&config)
+    }
+
+    pub fn save_to_file_path(&self, file_path: &str) -> Result<(), TinBindingsError> {
+        std::fs::write(file_path, self.to_config()).map_err(TinBindingsError::Io)
+    }
+}
+
+/**
+ The bindings in use, which of their triggers are held, and which actions changed since the last frame.
+ The context feeds it every event, and the events it returns go to the scene after the one that caused them.
+*/
+#[derive(Debug, Default)]
+pub(crate) struct TinActionState {
+    bindings: TinActionBindings,
+    held: HashMap<String, HashSet<TinInputTrigger>>,
+    pressed: HashSet<String>,
+    released: HashSet<String>,
+}
+
+impl TinActionState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get_bindings(&self) -> &TinActionBindings {
+        &self.bindings
+    }
+
+    /// Replace the bindings. Which actions are held starts over from the next press.
+    pub fn set_bindings(&mut self, bindings: TinActionBindings) {
+        self.bindings = bindings;
+        self.held.clear();
+        self.pressed.clear();
+        self.released.clear();
+    }
+
+    pub fn bind(&mut self, action: &str, trigger: TinInputTrigger) {
+        self.bindings.bind(action, trigger);
+    }
+
+    pub fn handle_event(&mut self, event: &TinEvent) -> Vec<TinEvent> {
+        match event {
+            TinEvent::KeyDown(key_event) if !key_event.is_repeat => {
+                self.press(|trigger| match trigger {
+                    TinInputTrigger::Key(key, modifiers) => {
+                        *key == key_event.key && key_event.modifiers.contains(*modifiers)
+                    }
+                    _ => false,
+                })
+            }
+            TinEvent::KeyUp(key_event) => self.release(|trigger| {
+                matches!(trigger, TinInputTrigger::Key(key, _) if *key == key_event.key)
+            }),
+            TinEvent::MouseDown(mouse) => {
+                self.press(|trigger| *trigger == TinInputTrigger::MouseButton(mouse.button))
+            }
+            TinEvent::MouseUp(mouse) => {
+                self.release(|trigger| *trigger == TinInputTrigger::MouseButton(mouse.button))
+            }
+            // Releases are never seen once the window loses focus, so nothing is held anymore.
+            TinEvent::WindowUnfocused => self.release(|_| true),
+            _ => Vec::new(),
+        }
+    }
+
+    fn press(&mut self, matches: impl Fn(&TinInputTrigger) -> bool) -> Vec<TinEvent> {
+        let mut events = Vec::new();
+        for (action, triggers) in &self.bindings.bindings {
+            for trigger in triggers.iter().filter(|t| matches(t)) {
+                let held = self.held.entry(action.clone()).or_default();
+                let was_active = !held.is_empty();
+                if held.insert(*trigger) && !was_active {
+                    self.pressed.insert(action.clone());
+                    events.push(TinEvent::ActionPressed(action.clone()));
+                }
+            }
+        }
+        events
+    }
+
+    fn release(&mut self, matches: impl Fn(&TinInputTrigger) -> bool) -> Vec<TinEvent> {
+        let mut events = Vec::new();
+        for (action, _) in &self.bindings.bindings {
+            if let Some(held) = self.held.get_mut(action) {
+                let was_active = !held.is_empty();
+                held.retain(|t| !matches(t));
+                if was_active && held.is_empty() {
+                    self.released.insert(action.clone());
+                    events.push(TinEvent::ActionReleased(action.clone()));
+                }
+            }
+        }
+        events
+    }
+
+    /// Forget which actions changed, once the frame that could see them is over.
+    pub fn end_frame(&mut self) {
+        self.pressed.clear();
+        self.released.clear();
+    }
+
+    pub fn is_action_down(&self, action: &str) -> bool {
+        self.held.get(action).map_or(false, |held| !held.is_empty())
+    }
+
+    pub fn action_just_pressed(&self, action: &str) -> bool {
+        self.pressed.contains(action)
+    }
+
+    pub fn action_just_released(&self, action: &str) -> bool {
+        self.released.contains(action)
+    }
+}
+
+// MARK: - Global action state
+
+/// Bind a trigger to a named action. The scene then gets `ActionPressed` and `ActionReleased` events for it.
+pub fn bind_action(action: &str, trigger: TinInputTrigger) {
+    get_tin_mut().actions.bind(action, trigger);
+}
+
+/// Replace every action binding.
+pub fn set_action_bindings(bindings: TinActionBindings) {
+    get_tin_mut().actions.set_bindings(bindings);
+}
+
+/// Replace every action binding with the ones in a file, so controls can be remapped without recompiling.
+pub fn load_action_bindings(file_path: &str) -> Result<(), TinBindingsError> {
+    let bindings = TinActionBindings::from_file_path(file_path)?;
+    set_action_bindings(bindings);
+    Ok(())
+}
+
+pub fn action_bindings() -> TinActionBindings {
+    get_tin().actions.get_bindings().clone()
+}
+
+/// True while any trigger bound to the action is held.
+pub fn is_action_down(action: &str) -> bool {
+    get_tin().actions.is_action_down(action)
+}
+
+/// True during the first frame after the action became active.
+pub fn action_just_pressed(action: &str) -> bool {
+    get_tin().actions.action_just_pressed(action)
+}
+
+/// True during the first frame after the action stopped being active.
+pub fn action_just_released(action: &str) -> bool {
+    get_tin().actions.action_just_released(action)
+}
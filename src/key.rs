@@ -1,3 +1,7 @@
+use crate::UInt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "recording", derive(serde::Serialize, serde::Deserialize))]
 pub enum TinKey {
     Zero,
     One,
@@ -69,6 +73,7 @@ pub enum TinKey {
     F22,
     F23,
     F24,
+    F25,
 
     Snapshot,
     Scroll,
@@ -96,6 +101,11 @@ pub enum TinKey {
     Backslash,
 
     Apostrophe,
+    Asterisk,
+    At,
+    Caret,
+    Plus,
+    Underline,
 
     NumLock,
     ScrollLock,
@@ -118,6 +128,7 @@ pub enum TinKey {
     NumpadEnter,
     NumpadEquals,
     NumpadDecimal,
+    NumpadComma,
 
     LAlt,
     LBracket,
@@ -129,7 +140,194 @@ pub enum TinKey {
     RControl,
     RShift,
 
+    /// The Command key on macOS and the Windows key elsewhere.
+    LSuper,
+    RSuper,
+    Menu,
+
     GraveAccent,
 
+    // Keys found on international keyboards.
+    World1,
+    World2,
+    Oem102,
+    AbntC1,
+    AbntC2,
+    Ax,
+    Compose,
+    Convert,
+    NoConvert,
+    Kana,
+    Kanji,
+    Yen,
+
+    // Media, system and browser keys.
+    Mute,
+    VolumeDown,
+    VolumeUp,
+    PlayPause,
+    MediaStop,
+    MediaSelect,
+    NextTrack,
+    PrevTrack,
+    Stop,
+    Calculator,
+    Mail,
+    MyComputer,
+    Power,
+    Sleep,
+    Wake,
+    Sysrq,
+    Copy,
+    Cut,
+    Paste,
+    NavigateBackward,
+    NavigateForward,
+    WebBack,
+    WebFavorites,
+    WebForward,
+    WebHome,
+    WebRefresh,
+    WebSearch,
+    WebStop,
+
     Unknown,
 }
+
+impl TinKey {
+    /// Every key, in declaration order.
+    pub const ALL: &'static [TinKey] = &[
+        TinKey::Zero, TinKey::One, TinKey::Two, TinKey::Three, TinKey::Four, TinKey::Five,
+        TinKey::Six, TinKey::Seven, TinKey::Eight, TinKey::Nine, TinKey::Left, TinKey::Right,
+        TinKey::Up, TinKey::Down, TinKey::Enter, TinKey::Space, TinKey::Backspace, TinKey::A,
+        TinKey::B, TinKey::C, TinKey::D, TinKey::E, TinKey::F, TinKey::G, TinKey::H, TinKey::I,
+        TinKey::J, TinKey::K, TinKey::L, TinKey::M, TinKey::N, TinKey::O, TinKey::P, TinKey::Q,
+        TinKey::R, TinKey::S, TinKey::T, TinKey::U, TinKey::V, TinKey::W, TinKey::X, TinKey::Y,
+        TinKey::Z, TinKey::F1, TinKey::F2, TinKey::F3, TinKey::F4, TinKey::F5, TinKey::F6,
+        TinKey::F7, TinKey::F8, TinKey::F9, TinKey::F10, TinKey::F11, TinKey::F12, TinKey::F13,
+        TinKey::F14, TinKey::F15, TinKey::F16, TinKey::F17, TinKey::F18, TinKey::F19, TinKey::F20,
+        TinKey::F21, TinKey::F22, TinKey::F23, TinKey::F24, TinKey::F25, TinKey::Snapshot,
+        TinKey::Scroll, TinKey::Pause, TinKey::Escape, TinKey::Insert, TinKey::Home, TinKey::Delete,
+        TinKey::End, TinKey::PageDown, TinKey::PageUp, TinKey::Semicolon, TinKey::Tab,
+        TinKey::Colon, TinKey::Equals, TinKey::Minus, TinKey::Comma, TinKey::Period, TinKey::Slash,
+        TinKey::Backslash, TinKey::Apostrophe, TinKey::Asterisk, TinKey::At, TinKey::Caret,
+        TinKey::Plus, TinKey::Underline, TinKey::NumLock, TinKey::ScrollLock, TinKey::CapsLock,
+        TinKey::Numpad0, TinKey::Numpad1, TinKey::Numpad2, TinKey::Numpad3, TinKey::Numpad4,
+        TinKey::Numpad5, TinKey::Numpad6, TinKey::Numpad7, TinKey::Numpad8, TinKey::Numpad9,
+        TinKey::NumpadAdd, TinKey::NumpadSubtract, TinKey::NumpadMultiply, TinKey::NumpadDivide,
+        TinKey::NumpadEnter, TinKey::NumpadEquals, TinKey::NumpadDecimal, TinKey::NumpadComma,
+        TinKey::LAlt, TinKey::LBracket, TinKey::LControl, TinKey::LShift, TinKey::RAlt,
+        TinKey::RBracket, TinKey::RControl, TinKey::RShift, TinKey::LSuper, TinKey::RSuper,
+        TinKey::Menu, TinKey::GraveAccent, TinKey::World1, TinKey::World2, TinKey::Oem102,
+        TinKey::AbntC1, TinKey::AbntC2, TinKey::Ax, TinKey::Compose, TinKey::Convert,
+        TinKey::NoConvert, TinKey::Kana, TinKey::Kanji, TinKey::Yen, TinKey::Mute,
+        TinKey::VolumeDown, TinKey::VolumeUp, TinKey::PlayPause, TinKey::MediaStop,
+        TinKey::MediaSelect, TinKey::NextTrack, TinKey::PrevTrack, TinKey::Stop, TinKey::Calculator,
+        TinKey::Mail, TinKey::MyComputer, TinKey::Power, TinKey::Sleep, TinKey::Wake, TinKey::Sysrq,
+        TinKey::Copy, TinKey::Cut, TinKey::Paste, TinKey::NavigateBackward, TinKey::NavigateForward,
+        TinKey::WebBack, TinKey::WebFavorites, TinKey::WebForward, TinKey::WebHome,
+        TinKey::WebRefresh, TinKey::WebSearch, TinKey::WebStop, TinKey::Unknown,
+    ];
+
+    /// The key's name as written in code, such as "Space" or "F1".
+    pub fn name(&self) -> String {
+        format!("{:?}", self)
+    }
+
+    /// The key with this name, ignoring case. The reverse of `name`.
+    pub fn from_name(name: &str) -> Option<TinKey> {
+        TinKey::ALL
+            .iter()
+            .copied()
+            .find(|key| key.name().eq_ignore_ascii_case(name))
+    }
+}
+
+/**
+ A key press or release.
+ `key` is what the key means under the current keyboard layout, while `scan_code` is where it is on the
+ keyboard whatever the layout, which suits bindings such as WASD. Scan codes are platform specific.
+*/
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "recording", derive(serde::Serialize, serde::Deserialize))]
+pub struct TinKeyEvent {
+    pub key: TinKey,
+    pub scan_code: UInt,
+    pub modifiers: TinModifiers,
+    /// True for the extra key downs sent while a key is held.
+    pub is_repeat: bool,
+}
+
+impl TinKeyEvent {
+    pub fn new(key: TinKey) -> Self {
+        Self {
+            key,
+            scan_code: 0,
+            modifiers: TinModifiers::NONE,
+            is_repeat: false,
+        }
+    }
+}
+
+/// The modifier keys held during an input event, as a set of bits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "recording", derive(serde::Serialize, serde::Deserialize))]
+pub struct TinModifiers(u8);
+
+impl TinModifiers {
+    pub const NONE: Self = Self(0);
+    pub const SHIFT: Self = Self(1);
+    pub const CONTROL: Self = Self(1 << 1);
+    pub const ALT: Self = Self(1 << 2);
+    /// The Command key on macOS and the Windows key elsewhere.
+    pub const LOGO: Self = Self(1 << 3);
+
+    pub fn from_bits(bits: u8) -> Self {
+        Self(bits & 0b1111)
+    }
+
+    pub fn bits(&self) -> u8 {
+        self.0
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// True if every modifier in `other` is held.
+    pub fn contains(&self, other: TinModifiers) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    pub fn insert(&mut self, other: TinModifiers) {
+        self.0 |= other.0;
+    }
+
+    pub fn remove(&mut self, other: TinModifiers) {
+        self.0 &= !other.0;
+    }
+
+    pub fn shift(&self) -> bool {
+        self.contains(Self::SHIFT)
+    }
+
+    pub fn control(&self) -> bool {
+        self.contains(Self::CONTROL)
+    }
+
+    pub fn alt(&self) -> bool {
+        self.contains(Self::ALT)
+    }
+
+    pub fn logo(&self) -> bool {
+        self.contains(Self::LOGO)
+    }
+}
+
+impl std::ops::BitOr for TinModifiers {
+    type Output = Self;
+
+    fn bitor(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
@@ -3,14 +3,18 @@
 pub(crate) mod backends;
 
 pub mod calculation;
+pub mod camera;
 #[cfg(feature = "time")]
 pub mod datetime;
+pub mod debug_text;
 pub mod draw;
 pub mod easing;
 
 pub mod event;
 pub use event::TinEvent;
 
+pub mod input;
+
 pub mod key;
 pub use key::TinKey;
 
@@ -28,11 +32,15 @@ pub mod image;
 pub mod point;
 #[cfg(feature = "random")]
 pub mod random; //TODO: Implement TRandom
+#[cfg(feature = "recording")]
+pub mod recording;
 pub mod scene;
 pub mod shapes;
+pub mod space;
 pub mod stopwatch;
 #[cfg(feature = "text")]
 pub mod text;
+pub mod timing;
 
 pub mod vector2;
 pub use vector2::TinVector2;
@@ -41,6 +49,8 @@ pub use vector2::TinVector2;
 pub(crate) mod vertex;
 
 pub mod view;
+pub mod viewport;
+pub mod window;
 
 pub type Double = f64;
 pub type Float = f32;
@@ -58,7 +68,10 @@ pub(crate) mod private {
 pub(crate) mod app;
 pub use app::*;
 
+#[cfg(not(feature = "headless"))]
 pub(crate) type CurrentBackend = backends::nannou::NannouBackend;
+#[cfg(feature = "headless")]
+pub(crate) type CurrentBackend = backends::software::SoftwareBackend;
 
 #[macro_use]
 #[cfg(test)]
@@ -42,6 +42,7 @@ pub trait TPoint: Sealed {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "recording", derive(serde::Serialize, serde::Deserialize))]
 pub struct TinPoint {
     x: Double,
     y: Double,
@@ -0,0 +1,270 @@
+use std::{
+    collections::VecDeque,
+    fs::File,
+    io::{BufRead, BufReader, LineWriter, Write},
+    time::Instant,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    context::{get_tin, get_tin_mut},
+    event::TinEvent,
+    Double, ULong,
+};
+
+/// One event, with when it arrived relative to the start of the recording.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TinRecordedEvent {
+    /// Frames since recording started. The event is delivered before the next update after that frame.
+    pub frame: ULong,
+    /// Seconds since recording started.
+    pub seconds: Double,
+    pub event: TinEvent,
+}
+
+#[derive(Debug)]
+pub enum TinRecordingError {
+    Io(std::io::Error),
+    Json(serde_json::Error),
+    /// A line that is not a recorded event, numbered from 1.
+    Parse { line: usize, error: serde_json::Error },
+}
+
+/**
+ The events a scene received, in order. Saved as one JSON object per line, so a recording
+ that was streamed to a file can be read back even if the app quit halfway through a frame.
+*/
+#[derive(Debug, Clone, Default)]
+pub struct TinEventRecording {
+    events: Vec<TinRecordedEvent>,
+}
+
+impl TinEventRecording {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get_events(&self) -> &Vec<TinRecordedEvent> {
+        &self.events
+    }
+
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    pub fn push(&mut self, recorded: TinRecordedEvent) {
+        self.events.push(recorded);
+    }
+
+    /// Frame of the last event, or 0 for an empty recording.
+    pub fn last_frame(&self) -> ULong {
+        self.events.last().map_or(0, |recorded| recorded.frame)
+    }
+
+    pub fn write_to(&self, mut writer: impl Write) -> Result<(), TinRecordingError> {
+        for recorded in &self.events {
+            write_line(&mut writer, recorded)?;
+        }
+        writer.flush().map_err(TinRecordingError::Io)
+    }
+
+    /// Reads one event per line. Blank lines are skipped.
+    pub fn read_from(reader: impl BufRead) -> Result<Self, TinRecordingError> {
+        let mut recording = Self::new();
+        for (index, line) in reader.lines().enumerate() {
+            let line = line.map_err(TinRecordingError::Io)?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let recorded = serde_json::from_str(&line)
+                .map_err(|error| TinRecordingError::Parse { line: index + 1, error })?;
+            recording.push(recorded);
+        }
+        Ok(recording)
+    }
+
+    pub fn save_to_file_path(&self, file_path: &str) -> Result<(), TinRecordingError> {
+        let file = File::create(file_path).map_err(TinRecordingError::Io)?;
+        self.write_to(std::io::BufWriter::new(file))
+    }
+
+    pub fn from_file_path(file_path: &str) -> Result<Self, TinRecordingError> {
+        let file = File::open(file_path).map_err(TinRecordingError::Io)?;
+        Self::read_from(BufReader::new(file))
+    }
+}
+
+fn write_line(writer: &mut impl Write, recorded: &TinRecordedEvent) -> Result<(), TinRecordingError> {
+    let line = serde_json::to_string(recorded).map_err(TinRecordingError::Json)?;
+    writeln!(writer, "{}", line).map_err(TinRecordingError::Io)
+}
+
+struct TinActiveRecording {
+    recording: TinEventRecording,
+    start_frame: ULong,
+    started: Instant,
+    file: Option<LineWriter<File>>,
+}
+
+struct TinPlayback {
+    pending: VecDeque<TinRecordedEvent>,
+    start_frame: ULong,
+}
+
+/// Whether events are being recorded or played back. Lives in the context.
+#[derive(Default)]
+pub(crate) struct TinRecordingState {
+    active: Option<TinActiveRecording>,
+    playback: Option<TinPlayback>,
+}
+
+impl TinRecordingState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn start(&mut self, frame: ULong, file: Option<LineWriter<File>>) {
+        self.active = Some(TinActiveRecording {
+            recording: TinEventRecording::new(),
+            start_frame: frame,
+            started: Instant::now(),
+            file,
+        });
+    }
+
+    pub fn stop(&mut self) -> Option<TinEventRecording> {
+        self.active.take().map(|active| active.recording)
+    }
+
+    pub fn play(&mut self, recording: TinEventRecording, frame: ULong) {
+        self.playback = Some(TinPlayback {
+            pending: recording.events.into(),
+            start_frame: frame,
+        });
+    }
+
+    pub fn is_recording(&self) -> bool {
+        self.active.is_some()
+    }
+
+    pub fn is_playing_back(&self) -> bool {
+        self.playback.is_some()
+    }
+
+    pub fn record(&mut self, frame: ULong, event: &TinEvent) {
+        let active = match &mut self.active {
+            Some(active) => active,
+            None => return,
+        };
+        let recorded = TinRecordedEvent {
+            frame: frame.saturating_sub(active.start_frame),
+            seconds: active.started.elapsed().as_secs_f64(),
+            event: event.clone(),
+        };
+        // A file that can't be written to stops being written, but the recording in memory carries on.
+        if let Some(file) = &mut active.file {
+            if let Err(error) = write_line(file, &recorded) {
+                eprintln!("Could not write recorded event: {:?}", error);
+                active.file = None;
+            }
+        }
+        active.recording.push(recorded);
+    }
+
+    /**
+     While a recording plays, live input would make the run differ from the recorded one, so only
+     closing, resizing and moving the window, or a change of screen density, get through.
+    */
+    pub fn ignores_live_event(&self, event: &TinEvent) -> bool {
+        self.is_playing_back()
+            && !matches!(
+                event,
+                TinEvent::WindowCloseRequested
+                    | TinEvent::WindowResized(..)
+                    | TinEvent::WindowMoved(..)
+                    | TinEvent::WindowPixelDensityChanged(..)
+            )
+    }
+
+    /// Every recorded event due by this frame. Playback ends once none are left.
+    pub fn take_due_events(&mut self, frame: ULong) -> Vec<TinEvent> {
+        let playback = match &mut self.playback {
+            Some(playback) => playback,
+            None => return Vec::new(),
+        };
+        let elapsed = frame.saturating_sub(playback.start_frame);
+        let mut due = Vec::new();
+        while playback.pending.front().map_or(false, |recorded| recorded.frame <= elapsed) {
+            if let Some(recorded) = playback.pending.pop_front() {
+                due.push(recorded.event);
+            }
+        }
+        if playback.pending.is_empty() {
+            self.playback = None;
+        }
+        due
+    }
+}
+
+// MARK: - Global recording state
+
+/// Start recording every event the scene receives, in memory. Replaces any recording in progress.
+pub fn start_recording() {
+    let mut tin = get_tin_mut();
+    let frame = tin.get_frame_count();
+    tin.recording.start(frame, None);
+}
+
+/**
+ Start recording every event the scene receives, writing each one to a file as it arrives,
+ so the file is complete even if the app is closed without stopping the recording.
+*/
+pub fn start_recording_to_file_path(file_path: &str) -> Result<(), TinRecordingError> {
+    let file = File::create(file_path).map_err(TinRecordingError::Io)?;
+    let mut tin = get_tin_mut();
+    let frame = tin.get_frame_count();
+    tin.recording.start(frame, Some(LineWriter::new(file)));
+    Ok(())
+}
+
+/// Stop recording, and return what was recorded.
+pub fn stop_recording() -> Option<TinEventRecording> {
+    get_tin_mut().recording.stop()
+}
+
+pub fn is_recording() -> bool {
+    get_tin().recording.is_recording()
+}
+
+/**
+ Deliver a recording's events to the scene at the same frames, counted from now, as they were
+ counted from the start of the recording. Start it from `setup` to replay a whole session.
+ Together with a fixed random seed, the scene runs the same way it did when it was recorded.
+*/
+pub fn play_recording(recording: TinEventRecording) {
+    let mut tin = get_tin_mut();
+    let frame = tin.get_frame_count();
+    tin.recording.play(recording, frame);
+}
+
+/// Read a recording from a file and play it. See `play_recording`.
+pub fn play_recording_from_file_path(file_path: &str) -> Result<(), TinRecordingError> {
+    let recording = TinEventRecording::from_file_path(file_path)?;
+    play_recording(recording);
+    Ok(())
+}
+
+/// Stop playing a recording, and let live input through again.
+pub fn stop_playback() {
+    get_tin_mut().recording.playback = None;
+}
+
+/// True until every event of the playing recording was delivered.
+pub fn is_playing_back() -> bool {
+    get_tin().recording.is_playing_back()
+}
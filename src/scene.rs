@@ -1,3 +1,8 @@
+use crate::context::{get_tin, get_tin_mut};
+
+mod manager;
+pub use manager::*;
+
 /// The user should implement this for the TinScene
 pub trait TScene {
     // TODO: Make the run method take in an implemented TScene type with a new() constructor instead of an instance of it, if possible, to reduce boilerplate.
@@ -6,10 +11,68 @@ pub trait TScene {
     // fn new(&mut self) -> Self;
 
     // setup() is called one time, immediately before first call to update().
-    fn setup() -> Self;
+    fn setup() -> Self
+    where
+        Self: Sized;
 
-    // Drawing code show go in update, or methods called during update.
+    /**
+     Called once a frame, unless paused. Drawing code can go here, or in `draw`.
+     The scene is not passed the frame time; read it from `timing::frame_time`, or `timing::delta_time`.
+    */
     fn update(&mut self);
 
+    /// Called after `update` every frame, even while paused. Drawing can go here to keep it apart from changing state.
+    fn draw(&mut self) {}
+
+    /**
+     Called before `update` once for every fixed timestep that passed, when a fixed timestep is set
+     with `Tin::fixed_timestep` or `timing::set_fixed_timestep`. `step` is always the same number of seconds,
+     which keeps physics and simulations stable whatever the frame rate.
+    */
+    fn fixed_update(&mut self, _step: crate::Double) {}
+
     fn on_event(&mut self, event: crate::TinEvent);
+
+    /**
+     Called with every event and the window it came from, for scenes shown in several windows.
+     Passes the event on to `on_event` unless implemented. `window::current_window` names the window too.
+    */
+    fn on_window_event(&mut self, _window: crate::window::TinWindowId, event: crate::TinEvent) {
+        self.on_event(event)
+    }
+
+    /// Called when the window changes size, with its new size in points, before the `WindowResized` event.
+    fn on_resize(&mut self, _width: crate::UInt, _height: crate::UInt) {}
+
+    /// Called at the start of the first frame after `pause`. Until `resume`, `update` and `fixed_update` aren't called.
+    fn on_pause(&mut self) {}
+
+    /// Called at the start of the first frame after `resume`.
+    fn on_resume(&mut self) {}
+
+    /// Called when the window gains or loses focus, before the `WindowFocused` or `WindowUnfocused` event.
+    fn on_focus_change(&mut self, _focused: bool) {}
+
+    /// Called once before the app exits. Close files, streams and connections here.
+    fn teardown(&mut self) {}
+}
+
+// MARK: - Global scene state
+
+/// Stop calling `update` and `fixed_update` from the next frame, and stop the frame clock. `draw` is still called.
+pub fn pause() {
+    get_tin_mut().paused = true;
+}
+
+pub fn resume() {
+    get_tin_mut().paused = false;
+}
+
+pub fn is_paused() -> bool {
+    get_tin().paused
+}
+
+/// Exit the app after the current frame, calling `teardown` first.
+pub fn quit() {
+    get_tin_mut().quit_requested = true;
 }
@@ -0,0 +1,456 @@
+use crate::{
+    color::{TColor, TinColor},
+    context::get_tin_mut,
+    draw::DrawCall,
+    easing,
+    scene::TScene,
+    shapes::TinRect,
+    timing::delta_time,
+    Double, TinEvent, UInt,
+};
+
+/// The side a wipe starts from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TinWipeDirection {
+    FromLeft,
+    FromRight,
+    FromTop,
+    FromBottom,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TinTransitionStyle {
+    /// Switch scenes at once.
+    Cut,
+    /// Draw the incoming scene over the outgoing one, fading it in.
+    Crossfade,
+    /**
+     A panel of the transition's color sweeps across and covers the outgoing scene,
+     then carries on across and uncovers the incoming one.
+    */
+    Wipe(TinWipeDirection),
+}
+
+/// How one scene changes into another.
+#[derive(Debug, Clone, Copy)]
+pub struct TinTransition {
+    pub style: TinTransitionStyle,
+    /// In seconds.
+    pub duration: Double,
+    /// One of the functions in `easing`, mapping the time passed from 0.0 to 1.0 onto the transition's progress.
+    pub easing: fn(Double, Double, Double) -> Double,
+    /// The color of the wipe panel.
+    pub color: TinColor,
+}
+
+impl TinTransition {
+    pub fn cut() -> Self {
+        Self {
+            style: TinTransitionStyle::Cut,
+            duration: 0.0,
+            easing: easing::linear,
+            color: TinColor::from_rgba(0.0, 0.0, 0.0, 1.0),
+        }
+    }
+
+    pub fn crossfade(duration: Double) -> Self {
+        Self {
+            style: TinTransitionStyle::Crossfade,
+            duration,
+            easing: easing::ease_in_out_quad,
+            ..Self::cut()
+        }
+    }
+
+    pub fn wipe(direction: TinWipeDirection, duration: Double) -> Self {
+        Self {
+            style: TinTransitionStyle::Wipe(direction),
+            duration,
+            easing: easing::ease_in_out_quad,
+            ..Self::cut()
+        }
+    }
+
+    pub fn with_easing(mut self, easing: fn(Double, Double, Double) -> Double) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    pub fn with_color(mut self, color: impl TColor) -> Self {
+        self.color = TinColor::from(color);
+        self
+    }
+}
+
+/// A change to the scene stack asked for by a scene, carried out by the scene manager after the scene returns.
+pub(crate) enum TinSceneCommand {
+    Push(fn() -> Box<dyn TScene>, TinTransition),
+    Pop(TinTransition),
+    Replace(fn() -> Box<dyn TScene>, TinTransition),
+}
+
+pub(crate) fn boxed_scene<S: TScene + 'static>() -> Box<dyn TScene> {
+    Box::new(S::setup())
+}
+
+struct TinActiveTransition {
+    transition: TinTransition,
+    elapsed: Double,
+    /// A popped or replaced scene, drawn until the transition ends. A pushed scene's outgoing scene stays on the stack.
+    removed: Option<Box<dyn TScene>>,
+}
+
+impl TinActiveTransition {
+    /// The eased progress, from 0.0 to 1.0.
+    fn progress(&self) -> Double {
+        if self.transition.duration <= 0.0 {
+            return 1.0;
+        }
+        let t = (self.elapsed / self.transition.duration).clamp(0.0, 1.0);
+        (self.transition.easing)(t, 0.0, 1.0)
+    }
+
+    fn is_finished(&self) -> bool {
+        self.elapsed >= self.transition.duration
+    }
+}
+
+/**
+ A stack of scenes, where the top scene gets the events and updates. Scenes are pushed, popped and replaced
+ with a transition, during which both the outgoing and incoming scenes update and draw.
+ Scenes in the stack can ask for changes with `push_scene`, `pop_scene` and `replace_scene`.
+ Only one manager should run at a time, since it carries out every scene's requests.
+*/
+pub struct TinSceneManager {
+    stack: Vec<Box<dyn TScene>>,
+    transition: Option<TinActiveTransition>,
+    outgoing_calls: Vec<DrawCall>,
+    incoming_calls: Vec<DrawCall>,
+}
+
+impl TinSceneManager {
+    pub fn new(scene: Box<dyn TScene>) -> Self {
+        Self {
+            stack: vec![scene],
+            transition: None,
+            outgoing_calls: Vec::new(),
+            incoming_calls: Vec::new(),
+        }
+    }
+
+    /// How many scenes are on the stack. It never drops below one, since the last scene can't be popped.
+    pub fn depth(&self) -> usize {
+        self.stack.len()
+    }
+
+    pub fn is_transitioning(&self) -> bool {
+        self.transition.is_some()
+    }
+
+    /// Put a scene on top of the stack. The scene under it stays and becomes the top again when it is popped.
+    pub fn push(&mut self, scene: Box<dyn TScene>, transition: TinTransition) {
+        self.finish_transition();
+        self.stack.push(scene);
+        self.start_transition(transition, None);
+    }
+
+    /// Remove the top scene, calling its `teardown` once the transition ends. The last scene can't be popped.
+    pub fn pop(&mut self, transition: TinTransition) {
+        self.finish_transition();
+        if self.stack.len() < 2 {
+            eprintln!("WARNING: The last scene can't be popped.");
+            return;
+        }
+        let removed = self.stack.pop();
+        self.start_transition(transition, removed);
+    }
+
+    /// Swap the top scene for another, calling the old one's `teardown` once the transition ends.
+    pub fn replace(&mut self, scene: Box<dyn TScene>, transition: TinTransition) {
+        self.finish_transition();
+        let removed = self.stack.pop();
+        self.stack.push(scene);
+        self.start_transition(transition, removed);
+    }
+
+    fn start_transition(&mut self, transition: TinTransition, removed: Option<Box<dyn TScene>>) {
+        if transition.style == TinTransitionStyle::Cut || transition.duration <= 0.0 {
+            if let Some(mut scene) = removed {
+                scene.teardown();
+            }
+            return;
+        }
+        self.transition = Some(TinActiveTransition {
+            transition,
+            elapsed: 0.0,
+            removed,
+        });
+    }
+
+    fn finish_transition(&mut self) {
+        if let Some(transition) = self.transition.take() {
+            if let Some(mut scene) = transition.removed {
+                scene.teardown();
+            }
+        }
+    }
+
+    /// Carry out the changes the scenes asked for since the last update.
+    fn apply_commands(&mut self) {
+        let commands: Vec<TinSceneCommand> = get_tin_mut().scene_commands.drain(..).collect();
+        for command in commands {
+            match command {
+                TinSceneCommand::Push(make_scene, transition) => self.push(make_scene(), transition),
+                TinSceneCommand::Pop(transition) => self.pop(transition),
+                TinSceneCommand::Replace(make_scene, transition) => self.replace(make_scene(), transition),
+            }
+        }
+    }
+
+    /// Run something on the outgoing scene of the transition, if there is one.
+    fn with_outgoing(&mut self, f: impl FnOnce(&mut dyn TScene)) {
+        let count = self.stack.len();
+        match &mut self.transition {
+            Some(TinActiveTransition {
+                removed: Some(scene),
+                ..
+            }) => f(scene.as_mut()),
+            Some(_) if count >= 2 => f(self.stack[count - 2].as_mut()),
+            _ => {}
+        }
+    }
+
+    fn top(&mut self) -> &mut dyn TScene {
+        self.stack
+            .last_mut()
+            .expect("The scene stack always holds a scene.")
+            .as_mut()
+    }
+
+    /// The draw calls queued since `mark`, taken off the queue.
+    fn take_calls_since(mark: usize) -> Vec<DrawCall> {
+        get_tin_mut().current_calls().drain(mark..).collect()
+    }
+
+    pub fn update(&mut self) {
+        self.apply_commands();
+        if let Some(transition) = &mut self.transition {
+            transition.elapsed += delta_time();
+        }
+        if self.transition.as_ref().map_or(false, |t| t.is_finished()) {
+            self.finish_transition();
+        }
+
+        if self.transition.is_none() {
+            self.top().update();
+        } else {
+            // Each scene's drawing is kept apart, to be put together in `draw`.
+            let mark = get_tin_mut().current_calls().len();
+            self.with_outgoing(|scene| scene.update());
+            self.outgoing_calls.extend(Self::take_calls_since(mark));
+            self.top().update();
+            self.incoming_calls.extend(Self::take_calls_since(mark));
+        }
+    }
+
+    pub fn fixed_update(&mut self, step: Double) {
+        self.top().fixed_update(step);
+    }
+
+    pub fn draw(&mut self) {
+        let active = self
+            .transition
+            .as_ref()
+            .map(|transition| (transition.progress(), transition.transition));
+        let (progress, transition) = match active {
+            Some(active) => active,
+            None => {
+                self.top().draw();
+                return;
+            }
+        };
+        let mark = get_tin_mut().current_calls().len();
+        self.with_outgoing(|scene| scene.draw());
+        self.outgoing_calls.extend(Self::take_calls_since(mark));
+        self.top().draw();
+        self.incoming_calls.extend(Self::take_calls_since(mark));
+
+        let (outgoing, incoming) = (
+            std::mem::take(&mut self.outgoing_calls),
+            std::mem::take(&mut self.incoming_calls),
+        );
+        let mut tin = get_tin_mut();
+        let (width, height) = (tin.width, tin.height);
+        let mapping = tin.space_mapping();
+        let calls = tin.current_calls();
+        match transition.style {
+            TinTransitionStyle::Cut => calls.extend(incoming),
+            TinTransitionStyle::Crossfade => {
+                calls.extend(outgoing);
+                calls.push_back(DrawCall::ResetState);
+                calls.push_back(DrawCall::Opacity(progress));
+                calls.extend(incoming);
+                calls.push_back(DrawCall::Opacity(1.0));
+            }
+            TinTransitionStyle::Wipe(direction) => {
+                // The panel covers the outgoing scene during the first half, and uncovers the incoming one during the second.
+                let (start, end) = if progress < 0.5 {
+                    calls.extend(outgoing);
+                    (0.0, progress * 2.0)
+                } else {
+                    calls.extend(incoming);
+                    (progress * 2.0 - 1.0, 1.0)
+                };
+                calls.push_back(DrawCall::ResetState);
+                calls.push_back(DrawCall::Fill(transition.color));
+                calls.push_back(DrawCall::StrokeDisable);
+                // The panel is laid out from the middle of the window, whatever space the scenes draw in.
+                let panel = wipe_panel(direction, start, end, width, height);
+                calls.push_back(DrawCall::Rect(mapping.inverse_rect(&panel)));
+            }
+        }
+    }
+
+    pub fn on_event(&mut self, event: TinEvent) {
+        self.top().on_event(event);
+    }
+
+    /// Every scene hears about a new size, so scenes under the top one are ready when they come back.
+    pub fn on_resize(&mut self, width: UInt, height: UInt) {
+        for scene in self.stack.iter_mut() {
+            scene.on_resize(width, height);
+        }
+    }
+
+    pub fn on_pause(&mut self) {
+        self.top().on_pause();
+    }
+
+    pub fn on_resume(&mut self) {
+        self.top().on_resume();
+    }
+
+    pub fn on_focus_change(&mut self, focused: bool) {
+        for scene in self.stack.iter_mut() {
+            scene.on_focus_change(focused);
+        }
+    }
+
+    /// Tear down every scene, from the top of the stack down.
+    pub fn teardown(&mut self) {
+        self.finish_transition();
+        while let Some(mut scene) = self.stack.pop() {
+            scene.teardown();
+        }
+    }
+}
+
+/**
+ The part of the window a wipe panel covers, from `start` to `end` as fractions of the way across
+ from the side it starts on. Drawing coordinates have their origin at the center of the window.
+*/
+pub(crate) fn wipe_panel(
+    direction: TinWipeDirection,
+    start: Double,
+    end: Double,
+    width: Double,
+    height: Double,
+) -> TinRect {
+    let middle = (start + end) / 2.0 - 0.5;
+    let fraction = end - start;
+    match direction {
+        TinWipeDirection::FromLeft => {
+            TinRect::from_dimensions(middle * width, 0.0, fraction * width, height)
+        }
+        TinWipeDirection::FromRight => {
+            TinRect::from_dimensions(-middle * width, 0.0, fraction * width, height)
+        }
+        TinWipeDirection::FromBottom => {
+            TinRect::from_dimensions(0.0, middle * height, width, fraction * height)
+        }
+        TinWipeDirection::FromTop => {
+            TinRect::from_dimensions(0.0, -middle * height, width, fraction * height)
+        }
+    }
+}
+
+/**
+ Runs a stack of scenes as a single scene, starting with `S`. Run it with `Tin::<TinSceneStack<S>>::app().run()`
+ to move between many scenes under one app.
+*/
+pub struct TinSceneStack<S: TScene + 'static> {
+    manager: TinSceneManager,
+    phantom: std::marker::PhantomData<S>,
+}
+
+impl<S: TScene + 'static> TinSceneStack<S> {
+    pub fn get_manager(&mut self) -> &mut TinSceneManager {
+        &mut self.manager
+    }
+}
+
+impl<S: TScene + 'static> TScene for TinSceneStack<S> {
+    fn setup() -> Self {
+        Self {
+            manager: TinSceneManager::new(boxed_scene::<S>()),
+            phantom: std::marker::PhantomData,
+        }
+    }
+
+    fn update(&mut self) {
+        self.manager.update();
+    }
+
+    fn draw(&mut self) {
+        self.manager.draw();
+    }
+
+    fn fixed_update(&mut self, step: Double) {
+        self.manager.fixed_update(step);
+    }
+
+    fn on_event(&mut self, event: TinEvent) {
+        self.manager.on_event(event);
+    }
+
+    fn on_resize(&mut self, width: UInt, height: UInt) {
+        self.manager.on_resize(width, height);
+    }
+
+    fn on_pause(&mut self) {
+        self.manager.on_pause();
+    }
+
+    fn on_resume(&mut self) {
+        self.manager.on_resume();
+    }
+
+    fn on_focus_change(&mut self, focused: bool) {
+        self.manager.on_focus_change(focused);
+    }
+
+    fn teardown(&mut self) {
+        self.manager.teardown();
+    }
+}
+
+// MARK: - Global scene stack requests
+
+/// Ask the running scene manager to set up a scene and put it on top of the stack.
+pub fn push_scene<S: TScene + 'static>(transition: TinTransition) {
+    get_tin_mut()
+        .scene_commands
+        .push(TinSceneCommand::Push(boxed_scene::<S>, transition));
+}
+
+/// Ask the running scene manager to remove the top scene.
+pub fn pop_scene(transition: TinTransition) {
+    get_tin_mut().scene_commands.push(TinSceneCommand::Pop(transition));
+}
+
+/// Ask the running scene manager to swap the top scene for a newly set up one.
+pub fn replace_scene<S: TScene + 'static>(transition: TinTransition) {
+    get_tin_mut()
+        .scene_commands
+        .push(TinSceneCommand::Replace(boxed_scene::<S>, transition));
+}
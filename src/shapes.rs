@@ -8,7 +8,7 @@ mod line;
 pub(crate) use line::*;
 
 mod path;
-pub(crate) use path::*;
+pub use path::*;
 
 mod rect;
 pub use rect::*;
@@ -1,8 +1,288 @@
-use crate::point::TPoint;
+use crate::{
+    brush::TBrush,
+    context::DrawState,
+    draw,
+    point::{TPoint, TinPoint},
+    shapes::TinRect,
+    vector2::TinVector2,
+    Double,
+};
+
+/// Number of straight pieces a cubic curve is split into when a path is flattened.
+pub const PATH_CURVE_STEPS: usize = 16;
+
+#[derive(Debug, Clone)]
+pub enum TinPathSegment {
+    /// A straight line to a point. The first vertex of a contour is where it starts.
+    Vertex(TinPoint),
+    /// A cubic Bézier curve from the previous point.
+    Curve {
+        to: TinPoint,
+        control1: TinPoint,
+        control2: TinPoint,
+    },
+}
+
+impl TinPathSegment {
+    pub fn end_point(&self) -> &TinPoint {
+        match self {
+            TinPathSegment::Vertex(point) => point,
+            TinPathSegment::Curve { to, .. } => to,
+        }
+    }
+}
+
+/**
+ A shape made of closed contours, each a list of straight and curved segments.
+ This is what `path_begin`, `path_vertex`, `path_add_curve`, `path_contour` and `path_end` build,
+ so a path can be stored, changed and drawn later. Contours are filled with the even-odd rule,
+ so a contour inside another one cuts a hole in it.
+*/
+#[derive(Debug, Clone, Default)]
+pub struct TinPath {
+    contours: Vec<Vec<TinPathSegment>>,
+}
+
+impl TinPath {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get_contours(&self) -> &Vec<Vec<TinPathSegment>> {
+        &self.contours
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.contours.iter().all(|c| c.is_empty())
+    }
+
+    pub fn clear(&mut self) {
+        self.contours.clear();
+    }
+
+    /// Start a new contour. The next vertex is where it begins.
+    pub fn begin_contour(&mut self) {
+        if self.contours.last().map_or(true, |c| !c.is_empty()) {
+            self.contours.push(Vec::new());
+        }
+    }
+
+    fn current_contour(&mut self) -> &mut Vec<TinPathSegment> {
+        if self.contours.is_empty() {
+            self.contours.push(Vec::new());
+        }
+        self.contours.last_mut().expect("A contour was just added.")
+    }
+
+    pub fn vertex(&mut self, x: Double, y: Double) {
+        self.current_contour()
+            .push(TinPathSegment::Vertex(TinPoint::from_coords(x, y)));
+    }
+
+    /// Add a cubic Bézier curve from the last point. A curve cannot start a contour, so one starting
+    /// an empty contour begins at its first control point.
+    pub fn add_curve(&mut self, to: &impl TPoint, control1: &impl TPoint, control2: &impl TPoint) {
+        let contour = self.current_contour();
+        if contour.is_empty() {
+            contour.push(TinPathSegment::Vertex(TinPoint::from_coords(
+                control1.get_x(),
+                control1.get_y(),
+            )));
+        }
+        contour.push(TinPathSegment::Curve {
+            to: TinPoint::from_coords(to.get_x(), to.get_y()),
+            control1: TinPoint::from_coords(control1.get_x(), control1.get_y()),
+            control2: TinPoint::from_coords(control2.get_x(), control2.get_y()),
+        });
+    }
+
+    /// Append every contour of another path.
+    pub fn append(&mut self, other: &TinPath) {
+        self.contours
+            .extend(other.contours.iter().filter(|c| !c.is_empty()).cloned());
+    }
+
+    /// Move every point, including curve controls, through a function. Useful for transforms and distortions.
+    pub fn map_points(&self, f: impl Fn(Double, Double) -> (Double, Double)) -> Self {
+        let map = |p: &TinPoint| {
+            let (x, y) = f(p.get_x(), p.get_y());
+            TinPoint::from_coords(x, y)
+        };
+        let contours = self
+            .contours
+            .iter()
+            .map(|contour| {
+                contour
+                    .iter()
+                    .map(|segment| match segment {
+                        TinPathSegment::Vertex(p) => TinPathSegment::Vertex(map(p)),
+                        TinPathSegment::Curve {
+                            to,
+                            control1,
+                            control2,
+                        } => TinPathSegment::Curve {
+                            to: map(to),
+                            control1: map(control1),
+                            control2: map(control2),
+                        },
+                    })
+                    .collect()
+            })
+            .collect();
+        Self { contours }
+    }
+
+    /// Every contour as a polyline, with each curve split into `curve_steps` straight pieces.
+    pub fn flatten(&self, curve_steps: usize) -> Vec<Vec<TinVector2>> {
+        let steps = curve_steps.max(1);
+        self.contours
+            .iter()
+            .filter(|c| !c.is_empty())
+            .map(|contour| {
+                let mut points: Vec<TinVector2> = Vec::new();
+                for segment in contour {
+                    match segment {
+                        TinPathSegment::Vertex(p) => {
+                            points.push(TinVector2::from_xy(p.get_x(), p.get_y()))
+                        }
+                        TinPathSegment::Curve {
+                            to,
+                            control1,
+                            control2,
+                        } => {
+                            let start = points.last().map_or((to.get_x(), to.get_y()), |p| (p.x, p.y));
+                            for step in 1..=steps {
+                                let (x, y) = cubic_point(
+                                    start,
+                                    (control1.get_x(), control1.get_y()),
+                                    (control2.get_x(), control2.get_y()),
+                                    (to.get_x(), to.get_y()),
+                                    step as Double / steps as Double,
+                                );
+                                points.push(TinVector2::from_xy(x, y));
+                            }
+                        }
+                    }
+                }
+                points
+            })
+            .collect()
+    }
+
+    /// The smallest rectangle holding every flattened point, or `None` for an empty path.
+    pub fn bounds(&self) -> Option<TinRect> {
+        let points: Vec<TinVector2> = self.flatten(PATH_CURVE_STEPS).into_iter().flatten().collect();
+        if points.is_empty() {
+            return None;
+        }
+        let min_x = points.iter().map(|p| p.x).fold(Double::INFINITY, Double::min);
+        let max_x = points.iter().map(|p| p.x).fold(Double::NEG_INFINITY, Double::max);
+        let min_y = points.iter().map(|p| p.y).fold(Double::INFINITY, Double::min);
+        let max_y = points.iter().map(|p| p.y).fold(Double::NEG_INFINITY, Double::max);
+        Some(TinRect::from_dimensions(
+            (min_x + max_x) / 2.0,
+            (min_y + max_y) / 2.0,
+            max_x - min_x,
+            max_y - min_y,
+        ))
+    }
+
+    /**
+     The path as SVG path data, for the "d" attribute of a `<path>`.
+     Points are written as they are, with y pointing up; flip them with `map_points` for SVG's y-down space.
+    */
+    pub fn to_svg_path_data(&self) -> String {
+        let mut data = Vec::new();
+        for contour in self.contours.iter().filter(|c| !c.is_empty()) {
+            for (index, segment) in contour.iter().enumerate() {
+                data.push(match segment {
+                    TinPathSegment::Vertex(p) if index == 0 => {
+                        format!("M{} {}", p.get_x(), p.get_y())
+                    }
+                    TinPathSegment::Vertex(p) => format!("L{} {}", p.get_x(), p.get_y()),
+                    TinPathSegment::Curve {
+                        to,
+                        control1,
+                        control2,
+                    } => format!(
+                        "C{} {} {} {} {} {}",
+                        control1.get_x(),
+                        control1.get_y(),
+                        control2.get_x(),
+                        control2.get_y(),
+                        to.get_x(),
+                        to.get_y()
+                    ),
+                });
+            }
+            data.push(String::from("Z"));
+        }
+        data.join(" ")
+    }
+
+    /// Queue the path with the path draw calls, using the current fill, stroke and transform.
+    pub fn draw(&self) {
+        draw::path_begin();
+        for contour in self.contours.iter().filter(|c| !c.is_empty()) {
+            draw::path_contour();
+            for segment in contour {
+                match segment {
+                    TinPathSegment::Vertex(p) => draw::path_vertex(p.get_x(), p.get_y()),
+                    TinPathSegment::Curve {
+                        to,
+                        control1,
+                        control2,
+                    } => draw::path_add_curve(to, control1, control2),
+                }
+            }
+        }
+        draw::path_end();
+    }
+}
+
+/// Point at `t` (0.0 to 1.0) along a cubic Bézier curve.
+pub(crate) fn cubic_point(
+    start: (Double, Double),
+    control1: (Double, Double),
+    control2: (Double, Double),
+    end: (Double, Double),
+    t: Double,
+) -> (Double, Double) {
+    let u = 1.0 - t;
+    let (a, b, c, d) = (u * u * u, 3.0 * u * u * t, 3.0 * u * t * t, t * t * t);
+    (
+        a * start.0 + b * control1.0 + c * control2.0 + d * end.0,
+        a * start.1 + b * control1.1 + c * control2.1 + d * end.1,
+    )
+}
+
+/**
+ Where a horizontal line crosses a set of closed contours, paired into the (start, end) x ranges
+ that are inside under the even-odd rule.
+*/
+pub(crate) fn even_odd_spans(contours: &[Vec<(Double, Double)>], y: Double) -> Vec<(Double, Double)> {
+    let mut crossings: Vec<Double> = Vec::new();
+    for points in contours {
+        for i in 0..points.len() {
+            let (x0, y0) = points[i];
+            let (x1, y1) = points[(i + 1) % points.len()];
+            if (y0 <= y && y < y1) || (y1 <= y && y < y0) {
+                crossings.push(x0 + (y - y0) / (y1 - y0) * (x1 - x0));
+            }
+        }
+    }
+    crossings.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+    crossings
+        .chunks(2)
+        .filter(|span| span.len() == 2)
+        .map(|span| (span[0], span[1]))
+        .collect()
+}
 
 pub(crate) trait PathRenderer {
     fn path_begin(&mut self);
     fn path_vertex(&mut self, at_point: &impl TPoint);
     fn path_add_curve(&mut self, to: &impl TPoint, control1: &impl TPoint, control2: &impl TPoint);
-    fn path_end(&mut self);
+    fn path_contour(&mut self);
+    fn path_end(&mut self, brush: TBrush, state: DrawState);
 }
@@ -0,0 +1,191 @@
+use crate::{
+    context::{get_tin, get_tin_mut, DrawState},
+    point::{TPoint, TinPoint},
+    shapes::{TinArc, TinRect, TinTriangle},
+    Double,
+};
+
+/// How drawing coordinates are placed in the window. Every backend draws the same picture for the same calls.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TinCoordinateSpace {
+    /// Points from the top left corner of the window, with y growing downwards, as in Processing.
+    TopLeftPixels,
+    /// Points from the middle of the window, with y growing upwards. The default.
+    #[default]
+    CenteredPixels,
+    /**
+     From the middle of the window, with y growing upwards, where the shorter side of the window
+     spans -1 to 1. Shapes keep their proportions whatever the window's aspect ratio.
+    */
+    Normalized,
+}
+
+/// Which point of a shape its x and y place, for `rect_mode` and `ellipse_mode`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TinShapeMode {
+    /// The corner with the smallest x and y: the top left with y growing downwards, the bottom left otherwise.
+    Corner,
+    Center,
+}
+
+impl TinShapeMode {
+    /// The middle of a shape placed at x, y in this mode.
+    pub(crate) fn center_of(&self, x: Double, y: Double, width: Double, height: Double) -> (Double, Double) {
+        match self {
+            TinShapeMode::Corner => (x + width / 2.0, y + height / 2.0),
+            TinShapeMode::Center => (x, y),
+        }
+    }
+}
+
+/**
+ Maps a coordinate space onto the one backends draw in: points from the middle of the window with y growing upwards.
+ Shapes are mapped before the backend sees them, and the transform is rewritten so that translating and rotating
+ work as the drawing space expects. Images and text are only moved, so they stay upright when y grows downwards.
+*/
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct TinSpaceMapping {
+    scale: Double,
+    flip_y: bool,
+    offset: (Double, Double),
+}
+
+impl TinSpaceMapping {
+    pub fn new(space: TinCoordinateSpace, width: Double, height: Double) -> Self {
+        match space {
+            TinCoordinateSpace::TopLeftPixels => Self {
+                scale: 1.0,
+                flip_y: true,
+                offset: (-width / 2.0, height / 2.0),
+            },
+            TinCoordinateSpace::CenteredPixels => Self {
+                scale: 1.0,
+                flip_y: false,
+                offset: (0.0, 0.0),
+            },
+            TinCoordinateSpace::Normalized => Self {
+                scale: width.min(height) / 2.0,
+                flip_y: false,
+                offset: (0.0, 0.0),
+            },
+        }
+    }
+
+    /// Like `new`, for a space laid over `rect`, given in backend coordinates, instead of the whole window.
+    pub fn within(space: TinCoordinateSpace, rect: &TinRect) -> Self {
+        let mut mapping = Self::new(space, rect.get_width(), rect.get_height());
+        mapping.offset.0 += rect.center.get_x();
+        mapping.offset.1 += rect.center.get_y();
+        mapping
+    }
+
+    fn y_sign(&self) -> Double {
+        if self.flip_y {
+            -1.0
+        } else {
+            1.0
+        }
+    }
+
+    pub fn point(&self, x: Double, y: Double) -> (Double, Double) {
+        (
+            x * self.scale + self.offset.0,
+            y * self.scale * self.y_sign() + self.offset.1,
+        )
+    }
+
+    /// Back from backend coordinates into the drawing space, such as for the mouse position.
+    pub fn inverse_point(&self, x: Double, y: Double) -> (Double, Double) {
+        if self.scale == 0.0 {
+            return (0.0, 0.0);
+        }
+        (
+            (x - self.offset.0) / self.scale,
+            (y - self.offset.1) / (self.scale * self.y_sign()),
+        )
+    }
+
+    pub fn tin_point(&self, point: &TinPoint) -> TinPoint {
+        let (x, y) = self.point(point.get_x(), point.get_y());
+        TinPoint::from_coords(x, y)
+    }
+
+    pub fn length(&self, length: Double) -> Double {
+        length * self.scale
+    }
+
+    pub fn rect(&self, rect: &TinRect) -> TinRect {
+        TinRect::from_dimensions_and_point(
+            self.tin_point(&rect.center),
+            self.length(rect.get_width()),
+            self.length(rect.get_height()),
+        )
+    }
+
+    /// A rect given in backend coordinates, in the drawing space.
+    pub fn inverse_rect(&self, rect: &TinRect) -> TinRect {
+        let (x, y) = self.inverse_point(rect.center.get_x(), rect.center.get_y());
+        let length = |length: Double| if self.scale == 0.0 { 0.0 } else { length / self.scale };
+        TinRect::from_dimensions(x, y, length(rect.get_width()), length(rect.get_height()))
+    }
+
+    /// Angles turn the other way when y grows downwards, so the arc is swept in reverse.
+    pub fn arc(&self, arc: &TinArc) -> TinArc {
+        let (start_angle, end_angle) = if self.flip_y {
+            (-arc.end_angle, -arc.start_angle)
+        } else {
+            (arc.start_angle, arc.end_angle)
+        };
+        TinArc::new(self.tin_point(&arc.center), self.length(arc.radius), start_angle, end_angle)
+    }
+
+    pub fn triangle(&self, triangle: &TinTriangle) -> TinTriangle {
+        TinTriangle::new(
+            self.tin_point(&triangle.point1),
+            self.tin_point(&triangle.point2),
+            self.tin_point(&triangle.point3),
+        )
+    }
+
+    /**
+     The transform that, applied to mapped points, lands where `state` applied in the drawing space would.
+     With the mapping `m(p) = k p + b`, that is `m ∘ state ∘ m⁻¹`: the rotation flips with y, and the
+     translation absorbs the offset.
+    */
+    pub fn state(&self, state: DrawState) -> DrawState {
+        let rotation = state.rotation * self.y_sign();
+        let translation = (
+            state.translation.0 * self.scale,
+            state.translation.1 * self.scale * self.y_sign(),
+        );
+        if state.scale == 0.0 {
+            return DrawState {
+                rotation,
+                scale: state.scale,
+                translation,
+            };
+        }
+        let (sin, cos) = rotation.sin_cos();
+        let (offset_x, offset_y) = self.offset;
+        let rotated_offset = (offset_x * cos - offset_y * sin, offset_x * sin + offset_y * cos);
+        DrawState {
+            rotation,
+            scale: state.scale,
+            translation: (
+                translation.0 - rotated_offset.0 + offset_x / state.scale,
+                translation.1 - rotated_offset.1 + offset_y / state.scale,
+            ),
+        }
+    }
+}
+
+// MARK: - Global coordinate space
+
+pub fn coordinate_space() -> TinCoordinateSpace {
+    get_tin().coordinate_space
+}
+
+/// Place everything drawn, and the mouse position, in another coordinate space.
+pub fn set_coordinate_space(space: TinCoordinateSpace) {
+    get_tin_mut().coordinate_space = space;
+}
@@ -1,6 +1,6 @@
 use std::time::{Duration, Instant};
 
-use crate::{Double, Float, UInt, ULong, UShort};
+use crate::{Double, UShort};
 
 /**
  A struct for simple timing.
@@ -43,27 +43,23 @@ impl TStopwatch {
 }
 
 use std::thread::sleep;
+/// Wait until a frame started at `last_frame_time` has lasted 1 / `target_fps` seconds. A target of 0 doesn't wait.
 pub(crate) fn pace_frames(target_fps: UShort, last_frame_time: Instant) {
+    if target_fps == 0 {
+        return;
+    }
     let stopwatch = TStopwatch {
         start_time: last_frame_time,
     };
+    let frame_time = Duration::from_secs_f64(1.0 / target_fps as Double);
 
-    let frametime_ms: UInt = match target_fps as UInt {
-        // Prevent divide by zero
-        0 => 1000 / 1,
-        _ => 1000 / target_fps as UInt,
-    };
-
-    let safe_sleep_period_ms = frametime_ms as Float * (9_f32 / 10_f32);
-    let safe_sleep_period: Duration = Duration::from_millis(safe_sleep_period_ms as ULong);
-
-    // TODO: Use stopwatch.rs methods to replicate this functionality
-
-    if stopwatch.get_elapsed_millisecs() < safe_sleep_period.as_millis() {
+    // Sleep through most of the wait, then spin for the rest, since sleeping can overshoot.
+    let elapsed = stopwatch.get_elapsed_time();
+    if elapsed < frame_time {
+        let safe_sleep_period = (frame_time - elapsed).mul_f32(9_f32 / 10_f32);
         sleep(safe_sleep_period);
     }
-
-    while (stopwatch.get_elapsed_millisecs() as UInt) < frametime_ms {
+    while stopwatch.get_elapsed_time() < frame_time {
         // Spinlock for whatever time is left after the thread sleep function
     }
 }
@@ -7,8 +7,32 @@ macro_rules! assert_roughly_eq {
     };
 }
 
+/// Held by tests that draw through the global context, so they don't take each other's draw calls.
+static GLOBAL_CONTEXT: std::sync::Mutex<()> = std::sync::Mutex::new(());
+
+fn lock_global_context() -> std::sync::MutexGuard<'static, ()> {
+    // A failed test poisons the lock, which says nothing about the context the next test draws in.
+    GLOBAL_CONTEXT.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
 mod calculation;
+mod camera;
 mod color;
+mod debug_text;
+#[cfg(feature = "image")]
+mod image;
+mod input;
+#[cfg(feature = "recording")]
+mod recording;
+mod scene;
+mod shapes;
+mod space;
+#[cfg(feature = "text")]
+mod text;
+mod timing;
 mod vector2;
+mod view;
+mod viewport;
+mod window;
 
 // TODO: Add test module for each module in library with utility methods that can be unit tested
@@ -0,0 +1,77 @@
+use super::assert_approx_eq;
+use crate::camera::TinCamera2D;
+use crate::context::DrawState;
+use crate::event::{TinEvent, TinMouseButton, TinMouseEvent};
+use crate::key::TinModifiers;
+use crate::point::{TPoint, TinPoint};
+use crate::shapes::TinRect;
+
+fn camera() -> TinCamera2D {
+    TinCamera2D::new()
+        .position(100.0, -40.0)
+        .zoom(2.5)
+        .rotation(0.4)
+        .viewport(TinRect::from_dimensions(30.0, 10.0, 200.0, 100.0))
+}
+
+#[test]
+fn test_world_and_screen_conversions_round_trip() {
+    let camera = camera();
+    let center = camera.world_to_screen(&camera.get_position());
+    assert_roughly_eq!(center.get_x(), 30.0);
+    assert_roughly_eq!(center.get_y(), 10.0);
+
+    let world = TinPoint::from_coords(-12.0, 64.0);
+    let back = camera.screen_to_world(&camera.world_to_screen(&world));
+    assert_roughly_eq!(back.get_x(), world.get_x());
+    assert_roughly_eq!(back.get_y(), world.get_y());
+}
+
+#[test]
+fn test_camera_composes_onto_the_draw_state() {
+    let camera = camera();
+    let state = DrawState {
+        rotation: -0.2,
+        scale: 0.5,
+        translation: (8.0, 3.0),
+    };
+    let composed = camera.get_state_around(&camera.get_viewport_center()).compose(state);
+    let (x, y) = state.apply(5.0, -7.0);
+    let expected = camera.world_to_screen(&(x, y));
+    let actual = composed.apply(5.0, -7.0);
+    assert_roughly_eq!(actual.0, expected.get_x());
+    assert_roughly_eq!(actual.1, expected.get_y());
+}
+
+#[test]
+fn test_zoom_keeps_the_point_under_the_mouse() {
+    let mut camera = camera();
+    let mouse = TinPoint::from_coords(70.0, -20.0);
+    let before = camera.screen_to_world(&mouse);
+    camera.zoom_at(1.5, &mouse);
+    let after = camera.screen_to_world(&mouse);
+    assert_roughly_eq!(camera.get_zoom(), 3.75);
+    assert_roughly_eq!(after.get_x(), before.get_x());
+    assert_roughly_eq!(after.get_y(), before.get_y());
+}
+
+#[test]
+fn test_dragging_pans_the_world_with_the_mouse() {
+    let mut camera = camera().pan_with_mouse(TinMouseButton::Left);
+    let start = TinPoint::from_coords(0.0, 0.0);
+    let grabbed = camera.screen_to_world(&start);
+    let mouse = |position: TinPoint| TinMouseEvent {
+        button: TinMouseButton::Left,
+        position,
+        modifiers: TinModifiers::NONE,
+    };
+    assert!(camera.handle_event(&TinEvent::MouseDown(mouse(start))));
+    let end = TinPoint::from_coords(25.0, 15.0);
+    assert!(camera.handle_event(&TinEvent::MouseMoved(end.clone())));
+    assert!(camera.handle_event(&TinEvent::MouseUp(mouse(end.clone()))));
+
+    let under_mouse = camera.screen_to_world(&end);
+    assert_roughly_eq!(under_mouse.get_x(), grabbed.get_x());
+    assert_roughly_eq!(under_mouse.get_y(), grabbed.get_y());
+    assert!(!camera.handle_event(&TinEvent::MouseMoved(start)));
+}
@@ -12,3 +12,59 @@ fn make_color_from_unsigned_int() {
     assert_roughly_eq!(b, test_val.get_blue());
     assert_roughly_eq!(a, test_val.get_alpha());
 }
+
+#[test]
+fn make_color_from_hsv_and_hsl() {
+    let orange = TinColor::from_hsv(30.0, 1.0, 1.0);
+    assert_roughly_eq!(orange.get_red(), 1.0);
+    assert_roughly_eq!(orange.get_green(), 0.5);
+    assert_roughly_eq!(orange.get_blue(), 0.0);
+
+    let same = TinColor::from_hsl(30.0, 1.0, 0.5);
+    assert_roughly_eq!(same.get_red(), orange.get_red());
+    assert_roughly_eq!(same.get_green(), orange.get_green());
+    assert_roughly_eq!(same.get_blue(), orange.get_blue());
+
+    // Hues wrap around the color wheel.
+    let blue = TinColor::from_hsv(-120.0, 1.0, 1.0);
+    assert_roughly_eq!(blue.get_blue(), 1.0);
+    assert_roughly_eq!(blue.get_red(), 0.0);
+}
+
+#[test]
+fn set_hsv_components_keeps_the_others() {
+    let mut color = TinColor::from_hsva(200.0, 0.5, 0.8, 0.3);
+    color.set_hue(90.0);
+    assert_roughly_eq!(color.get_hue(), 90.0);
+    assert_roughly_eq!(color.get_saturation(), 0.5);
+    assert_roughly_eq!(color.get_value(), 0.8);
+    assert_roughly_eq!(color.get_alpha(), 0.3);
+
+    color.set_saturation(1.0);
+    color.set_value(0.5);
+    assert_roughly_eq!(color.get_hue(), 90.0);
+    assert_roughly_eq!(color.get_saturation(), 1.0);
+    assert_roughly_eq!(color.get_value(), 0.5);
+
+    color.set_lightness(0.75);
+    assert_roughly_eq!(color.lightness(), 0.75);
+    assert_roughly_eq!(color.get_hue(), 90.0);
+}
+
+#[test]
+fn setting_value_to_zero_loses_hue_and_saturation() {
+    let mut color = TinColor::from_hsv(200.0, 0.5, 0.8);
+    color.set_value(0.0);
+    color.set_value(0.5);
+    // Black stores no hue or saturation, so the color comes back as a gray.
+    assert_roughly_eq!(color.get_saturation(), 0.0);
+    assert_roughly_eq!(color.get_red(), 0.5);
+    assert_roughly_eq!(color.get_green(), 0.5);
+    assert_roughly_eq!(color.get_blue(), 0.5);
+
+    let mut color = TinColor::from_hsl(200.0, 0.5, 0.4);
+    color.set_lightness(1.0);
+    color.set_lightness(0.5);
+    assert_roughly_eq!(color.get_saturation(), 0.0);
+    assert_roughly_eq!(color.get_red(), 0.5);
+}
@@ -0,0 +1,25 @@
+use super::assert_approx_eq;
+use crate::debug_text::{debug_glyph, debug_text_rects, debug_text_size};
+use crate::point::TPoint;
+
+#[test]
+fn test_debug_glyph_falls_back_to_question_mark() {
+    assert_eq!(debug_glyph('\u{e9}'), debug_glyph('?'));
+    assert_eq!(debug_glyph(' '), &[0; 8]);
+}
+
+#[test]
+fn test_debug_text_size() {
+    assert_eq!(debug_text_size("abc", 1.0), (24.0, 8.0));
+    assert_eq!(debug_text_size("abc\nd", 2.0), (48.0, 36.0));
+}
+
+#[test]
+fn test_debug_text_rects_merge_rows() {
+    // '-' is a single row of six lit pixels, on the fourth row from the top.
+    let rects = debug_text_rects("-", 10.0, 20.0, 2.0);
+    assert_eq!(rects.len(), 1);
+    assert_roughly_eq!(rects[0].get_width(), 12.0);
+    assert_roughly_eq!(rects[0].center.get_y(), 20.0 - 3.0 * 2.0 - 1.0);
+    assert_roughly_eq!(rects[0].center.get_x(), 10.0 + 6.0);
+}
@@ -0,0 +1,236 @@
+use super::assert_approx_eq;
+use crate::draw::{background, fill_color_from_rgba, rect, stroke_disable};
+use crate::image::{
+    clamped_gif_delay, frame_index_at, grid_cells, render_to_image, TinAnimatedImage,
+    TinBlendMode, TinImage, TinImageStore, TinPlaybackMode, TinSpriteSheet, TinTextureCache,
+};
+use image::{DynamicImage, GenericImageView, Rgba, RgbaImage};
+
+fn solid(width: u32, height: u32, pixel: [u8; 4]) -> TinImage {
+    TinImage::from_image(DynamicImage::ImageRgba8(RgbaImage::from_pixel(
+        width,
+        height,
+        Rgba(pixel),
+    )))
+}
+
+#[test]
+fn test_blend_channel_modes() {
+    assert_roughly_eq!(TinBlendMode::Multiply.blend_channel(0.5, 0.5), 0.25);
+    assert_roughly_eq!(TinBlendMode::Screen.blend_channel(0.5, 0.5), 0.75);
+    assert_roughly_eq!(TinBlendMode::Add.blend_channel(0.75, 0.5), 1.0);
+    assert_roughly_eq!(TinBlendMode::Difference.blend_channel(0.2, 0.7), 0.5);
+}
+
+#[test]
+fn test_blend_with_offset_and_opacity() {
+    let mut backdrop = solid(4, 4, [0, 0, 0, 255]);
+    let source = solid(2, 2, [255, 255, 255, 255]);
+    backdrop.blend(&source, 3, 3, 0.5, TinBlendMode::Normal, None);
+
+    assert_eq!(backdrop.image.get_pixel(0, 0), Rgba([0, 0, 0, 255]));
+    assert_eq!(backdrop.image.get_pixel(3, 3), Rgba([128, 128, 128, 255]));
+}
+
+#[test]
+fn test_split_and_merge_channels() {
+    let original = solid(3, 2, [10, 20, 30, 40]);
+    let [r, g, b, a] = original.split_channels();
+    let merged = TinImage::merge_channels(&r, &g, &b, &a).unwrap();
+    assert_eq!(merged.image.get_pixel(2, 1), Rgba([10, 20, 30, 40]));
+
+    let wrong_size = solid(1, 1, [0, 0, 0, 0]);
+    assert!(TinImage::merge_channels(&r, &g, &b, &wrong_size).is_err());
+}
+
+#[test]
+fn test_image_store_handles() {
+    let mut store = TinImageStore::new();
+    let first = store.add(solid(2, 2, [0, 0, 0, 255]));
+    let second = store.add(solid(3, 1, [0, 0, 0, 255]));
+    assert_ne!(first.get_id(), second.get_id());
+    assert_eq!(store.get(second.get_id()).unwrap().get_width(), 3);
+
+    assert!(store.remove(&first));
+    assert!(!store.remove(&first));
+    assert!(store.get(first.get_id()).is_none());
+    assert_eq!(store.drain_evicted(), vec![first.get_id()]);
+    assert!(store.drain_evicted().is_empty());
+
+    // Outstanding handles keep the image alive after removal.
+    assert_eq!(first.get_height(), 2);
+}
+
+#[test]
+fn test_grid_cells_skip_partial_frames() {
+    let cells = grid_cells(10, 7, 4, 3, 1);
+    assert_eq!(cells, vec![(0, 0, 4, 3), (5, 0, 4, 3), (0, 4, 4, 3), (5, 4, 4, 3)]);
+}
+
+#[test]
+fn test_frame_index_playback_modes() {
+    let durations = [1.0, 1.0, 1.0];
+    assert_eq!(frame_index_at(&durations, 0.5, TinPlaybackMode::Loop), 0);
+    assert_eq!(frame_index_at(&durations, 3.5, TinPlaybackMode::Loop), 0);
+    assert_eq!(frame_index_at(&durations, 3.5, TinPlaybackMode::PingPong), 1);
+    assert_eq!(frame_index_at(&durations, 4.5, TinPlaybackMode::PingPong), 0);
+    assert_eq!(frame_index_at(&durations, 10.0, TinPlaybackMode::Once), 2);
+}
+
+#[test]
+fn test_animated_image_sampling() {
+    let animation = TinAnimatedImage::from_frames(vec![
+        (solid(1, 1, [0, 0, 0, 255]), 0.1),
+        (solid(2, 1, [0, 0, 0, 255]), 0.3),
+    ]);
+    assert_roughly_eq!(animation.get_duration(), 0.4);
+
+    let at_time = animation.frame_at_time(0.2, TinPlaybackMode::Loop).unwrap();
+    assert_eq!(at_time.get_width(), 2);
+    assert_eq!(animation.frame_at_count(4).unwrap().get_width(), 1);
+    assert_eq!(animation.frame_at_count(5).unwrap().get_width(), 2);
+}
+
+#[test]
+fn test_render_to_image() {
+    let _context = super::lock_global_context();
+    let rendered = render_to_image(10, 10, || {
+        background(0.0, 0.0, 0.0);
+        stroke_disable();
+        fill_color_from_rgba(1.0, 0.0, 0.0, 1.0);
+        rect(0.0, 0.0, 4.0, 4.0);
+    });
+    assert_eq!(rendered.get_width(), 10);
+    assert_eq!(rendered.image.get_pixel(5, 5), Rgba([255, 0, 0, 255]));
+    assert_eq!(rendered.image.get_pixel(0, 0), Rgba([0, 0, 0, 255]));
+}
+
+#[test]
+fn test_texture_cache_uploads_once_per_handle() {
+    let mut store = TinImageStore::new();
+    let handle = store.add(solid(2, 2, [0, 0, 0, 255]));
+    let mut cache = TinTextureCache::new();
+    let mut uploads = 0;
+    cache.get_or_insert_with(&handle, |_| {
+        uploads += 1;
+        "texture"
+    });
+    let cached = *cache.get_or_insert_with(&handle.clone(), |_| {
+        uploads += 1;
+        "another texture"
+    });
+    assert_eq!(uploads, 1);
+    assert_eq!(cached, "texture");
+
+    // Once evicted, the next draw uploads the image again.
+    cache.evict(handle.get_id());
+    cache.get_or_insert_with(&handle, |_| {
+        uploads += 1;
+        "texture"
+    });
+    assert_eq!(uploads, 2);
+}
+
+/// Four pixels wide and two high: red on the left half, blue on the right.
+fn red_and_blue() -> TinImage {
+    TinImage::from_image(DynamicImage::ImageRgba8(RgbaImage::from_fn(4, 2, |x, _| {
+        if x < 2 {
+            Rgba([255, 0, 0, 255])
+        } else {
+            Rgba([0, 0, 255, 255])
+        }
+    })))
+}
+
+#[test]
+fn test_sprite_sheet_from_grid_crops_frames_in_order() {
+    let mut sheet = TinSpriteSheet::from_grid(&red_and_blue(), 2, 2, 0);
+    assert_eq!(sheet.names(), vec!["0", "1"]);
+    let right = sheet.frame("1").unwrap();
+    assert_eq!((right.get_width(), right.get_height()), (2, 2));
+    assert_eq!(right.get_image().image.get_pixel(0, 0), Rgba([0, 0, 255, 255]));
+
+    let ids: Vec<_> = (0..sheet.len()).map(|i| sheet.frame_at(i).unwrap().get_id()).collect();
+    sheet.unload();
+    assert!(sheet.is_empty());
+    let tin = crate::context::get_tin();
+    assert!(ids.iter().all(|id| tin.images.get(*id).is_none()));
+}
+
+#[cfg(feature = "atlas")]
+#[test]
+fn test_sprite_sheet_from_atlas_json() {
+    let json = r#"{"frames": {
+        "walk_1": {"frame": {"x": 2, "y": 0, "w": 2, "h": 2}, "duration": 250},
+        "walk_0": {"frame": {"x": 0, "y": 0, "w": 2, "h": 1}}
+    }}"#;
+    let sheet = TinSpriteSheet::from_atlas_json(&red_and_blue(), json).unwrap();
+    assert_eq!(sheet.names(), vec!["walk_0", "walk_1"]);
+    assert_eq!(sheet.frame("walk_0").unwrap().get_height(), 1);
+    let walk = sheet.frame("walk_1").unwrap();
+    assert_eq!(walk.get_image().image.get_pixel(1, 1), Rgba([0, 0, 255, 255]));
+
+    let mut animation = sheet.animation_all(0.1, TinPlaybackMode::Loop);
+    animation.advance(0.15);
+    assert_eq!(animation.get_frame_index(), 1);
+}
+
+#[cfg(feature = "atlas")]
+#[test]
+fn test_sprite_sheet_rejects_bad_atlases() {
+    use crate::image::TinAtlasError;
+
+    let image = red_and_blue();
+    assert!(matches!(
+        TinSpriteSheet::from_atlas_json(&image, "{\"frames\": "),
+        Err(TinAtlasError::Json(_))
+    ));
+    assert!(matches!(
+        TinSpriteSheet::from_atlas_json(&image, "{\"sprites\": []}"),
+        Err(TinAtlasError::Format(_))
+    ));
+    let duplicated = r#"{"frames": [
+        {"filename": "idle", "frame": {"x": 0, "y": 0, "w": 2, "h": 2}},
+        {"filename": "idle", "frame": {"x": 2, "y": 0, "w": 2, "h": 2}}
+    ]}"#;
+    match TinSpriteSheet::from_atlas_json(&image, duplicated) {
+        Err(TinAtlasError::DuplicateName(name)) => assert_eq!(name, "idle"),
+        other => panic!("expected a duplicate name error, got {:?}", other.map(|sheet| sheet.names().len())),
+    }
+}
+
+#[test]
+fn test_zero_gif_delays_are_clamped() {
+    assert_roughly_eq!(clamped_gif_delay(0.0), 0.1);
+    assert_roughly_eq!(clamped_gif_delay(0.01), 0.1);
+    assert_roughly_eq!(clamped_gif_delay(0.05), 0.05);
+
+    // With clamped delays, an animation made of zero-delay frames moves on.
+    let frames = vec![
+        (solid(1, 1, [0, 0, 0, 255]), clamped_gif_delay(0.0)),
+        (solid(1, 1, [255, 255, 255, 255]), clamped_gif_delay(0.0)),
+    ];
+    let animation = TinAnimatedImage::from_frames(frames);
+    let second = animation.frame_at_time(0.15, TinPlaybackMode::Loop).unwrap();
+    assert_eq!(second.image.get_pixel(0, 0), Rgba([255, 255, 255, 255]));
+}
+
+#[test]
+fn test_animated_image_registers_its_frames_once() {
+    let mut animation = TinAnimatedImage::from_frames(vec![
+        (solid(1, 1, [0, 0, 0, 255]), 0.1),
+        (solid(1, 1, [255, 255, 255, 255]), 0.1),
+    ]);
+    let frame_ids = |mut sprite: crate::image::TinAnimatedSprite| {
+        let first = sprite.get_current_frame().unwrap().get_id();
+        sprite.advance(0.15);
+        vec![first, sprite.get_current_frame().unwrap().get_id()]
+    };
+    let ids = frame_ids(animation.to_sprite(TinPlaybackMode::Loop));
+    assert_ne!(ids[0], ids[1]);
+    assert_eq!(frame_ids(animation.to_sprite(TinPlaybackMode::Once)), ids);
+
+    animation.unload();
+    let tin = crate::context::get_tin();
+    assert!(ids.iter().all(|id| tin.images.get(*id).is_none()));
+}
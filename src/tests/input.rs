@@ -0,0 +1,145 @@
+use crate::event::{TinEvent, TinMouseButton, TinMouseEvent};
+use crate::input::{
+    TinActionBindings, TinActionState, TinBindingsError, TinInputState, TinInputTrigger,
+};
+use crate::key::{TinKey, TinKeyEvent, TinModifiers};
+use crate::point::{TPoint, TinPoint};
+
+fn left_button() -> TinMouseEvent {
+    TinMouseEvent {
+        button: TinMouseButton::Left,
+        position: TinPoint::from_coords(0.0, 0.0),
+        modifiers: TinModifiers::NONE,
+    }
+}
+
+#[test]
+fn test_key_presses_last_one_frame() {
+    let mut input = TinInputState::new();
+    input.handle_event(&TinEvent::KeyDown(TinKeyEvent::new(TinKey::A)));
+    assert!(input.is_key_down(TinKey::A));
+    assert!(input.key_just_pressed(TinKey::A));
+
+    input.end_frame();
+    // A repeated key down is not a new press.
+    input.handle_event(&TinEvent::KeyDown(TinKeyEvent::new(TinKey::A)));
+    assert!(input.is_key_down(TinKey::A));
+    assert!(!input.key_just_pressed(TinKey::A));
+
+    input.handle_event(&TinEvent::KeyUp(TinKeyEvent::new(TinKey::A)));
+    assert!(!input.is_key_down(TinKey::A));
+    assert!(input.key_just_released(TinKey::A));
+    input.end_frame();
+    assert!(!input.key_just_released(TinKey::A));
+}
+
+#[test]
+fn test_losing_focus_releases_everything() {
+    let mut input = TinInputState::new();
+    input.handle_event(&TinEvent::KeyDown(TinKeyEvent::new(TinKey::Space)));
+    input.handle_event(&TinEvent::MouseDown(left_button()));
+    assert!(input.any_button_down());
+
+    input.handle_event(&TinEvent::WindowUnfocused);
+    assert!(!input.is_key_down(TinKey::Space));
+    assert!(!input.is_button_down(TinMouseButton::Left));
+    assert!(input.button_just_released(TinMouseButton::Left));
+}
+
+#[test]
+fn test_action_bindings_parse_and_write_config() {
+    let config = "# Controls\njump = Space, W\n\nsave = ctrl+s\nnext_palette = MouseRight, Mouse4\n";
+    let bindings = TinActionBindings::from_config(config).unwrap();
+    assert_eq!(bindings.action_names(), vec!["jump", "save", "next_palette"]);
+    assert_eq!(
+        bindings.get_triggers("save"),
+        &[TinInputTrigger::key_with_modifiers(TinKey::S, TinModifiers::CONTROL)]
+    );
+    assert_eq!(
+        bindings.get_triggers("next_palette")[1],
+        TinInputTrigger::mouse_button(TinMouseButton::Other(4))
+    );
+    assert_eq!(TinActionBindings::from_config(&bindings.to_config()).unwrap(), bindings);
+
+    match TinActionBindings::from_config("jump = Space\nfly = Hyper+F") {
+        Err(TinBindingsError::Parse { line, .. }) => assert_eq!(line, 2),
+        other => panic!("Expected a parse error, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_action_stays_down_while_any_trigger_is_held() {
+    let mut bindings = TinActionBindings::new();
+    bindings.bind("jump", TinInputTrigger::key(TinKey::Space));
+    bindings.bind("jump", TinInputTrigger::mouse_button(TinMouseButton::Left));
+    bindings.bind("save", TinInputTrigger::key_with_modifiers(TinKey::S, TinModifiers::CONTROL));
+    let mut actions = TinActionState::new();
+    actions.set_bindings(bindings);
+
+    let pressed = actions.handle_event(&TinEvent::KeyDown(TinKeyEvent::new(TinKey::Space)));
+    assert!(matches!(pressed.as_slice(), [TinEvent::ActionPressed(name)] if name == "jump"));
+    assert!(actions.action_just_pressed("jump"));
+    assert!(actions.handle_event(&TinEvent::MouseDown(left_button())).is_empty());
+    assert!(actions.handle_event(&TinEvent::KeyUp(TinKeyEvent::new(TinKey::Space))).is_empty());
+    assert!(actions.is_action_down("jump"));
+
+    let released = actions.handle_event(&TinEvent::MouseUp(left_button()));
+    assert!(matches!(released.as_slice(), [TinEvent::ActionReleased(name)] if name == "jump"));
+    actions.end_frame();
+    assert!(!actions.action_just_pressed("jump"));
+
+    // A key bound with modifiers needs them held when it is pressed.
+    assert!(actions.handle_event(&TinEvent::KeyDown(TinKeyEvent::new(TinKey::S))).is_empty());
+    actions.handle_event(&TinEvent::KeyUp(TinKeyEvent::new(TinKey::S)));
+    let mut save = TinKeyEvent::new(TinKey::S);
+    save.modifiers = TinModifiers::CONTROL;
+    assert_eq!(actions.handle_event(&TinEvent::KeyDown(save)).len(), 1);
+    assert!(actions.is_action_down("save"));
+}
+
+#[test]
+fn test_modifiers_insert_and_contain() {
+    assert!(TinModifiers::NONE.is_empty());
+    assert_eq!(TinModifiers::default(), TinModifiers::NONE);
+    // Every set contains the empty set.
+    assert!(TinModifiers::SHIFT.contains(TinModifiers::NONE));
+
+    let mut modifiers = TinModifiers::NONE;
+    modifiers.insert(TinModifiers::SHIFT);
+    modifiers.insert(TinModifiers::LOGO);
+    assert!(modifiers.shift() && modifiers.logo());
+    assert!(!modifiers.control() && !modifiers.alt());
+    assert!(modifiers.contains(TinModifiers::SHIFT | TinModifiers::LOGO));
+    assert!(!modifiers.contains(TinModifiers::SHIFT | TinModifiers::ALT));
+
+    modifiers.remove(TinModifiers::SHIFT);
+    assert_eq!(modifiers, TinModifiers::LOGO);
+    assert_eq!(TinModifiers::from_bits(0xff).bits(), 0b1111);
+}
+
+#[test]
+fn test_nannou_modifiers_and_buttons_convert() {
+    use nannou::{prelude::MouseButton, winit::event::ModifiersState};
+
+    let state = ModifiersState::SHIFT | ModifiersState::CTRL;
+    assert_eq!(
+        TinModifiers::from(state),
+        TinModifiers::SHIFT | TinModifiers::CONTROL
+    );
+    assert_eq!(TinModifiers::from(ModifiersState::empty()), TinModifiers::NONE);
+    assert_eq!(TinMouseButton::from(MouseButton::Middle), TinMouseButton::Middle);
+    assert_eq!(TinMouseButton::from(MouseButton::Other(4)), TinMouseButton::Other(4));
+}
+
+#[cfg(feature = "luminance_backend")]
+#[test]
+fn test_glfw_modifiers_and_buttons_convert() {
+    let mods = glfw::Modifiers::Alt | glfw::Modifiers::Super;
+    assert_eq!(TinModifiers::from(mods), TinModifiers::ALT | TinModifiers::LOGO);
+    assert_eq!(TinModifiers::from(glfw::Modifiers::empty()), TinModifiers::NONE);
+    assert_eq!(TinMouseButton::from(glfw::MouseButton::Button1), TinMouseButton::Left);
+    assert_eq!(TinMouseButton::from(glfw::MouseButton::Button2), TinMouseButton::Right);
+    assert_eq!(TinMouseButton::from(glfw::MouseButton::Button3), TinMouseButton::Middle);
+    // glfw numbers buttons from 0, so the fourth is Other(3).
+    assert_eq!(TinMouseButton::from(glfw::MouseButton::Button4), TinMouseButton::Other(3));
+}
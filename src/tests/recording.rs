@@ -0,0 +1,56 @@
+use crate::event::TinEvent;
+use crate::key::{TinKey, TinKeyEvent, TinModifiers};
+use crate::point::{TPoint, TinPoint};
+use crate::recording::{TinEventRecording, TinRecordingState};
+
+fn key_down(key: TinKey) -> TinEvent {
+    let mut event = TinKeyEvent::new(key);
+    event.modifiers = TinModifiers::SHIFT;
+    TinEvent::KeyDown(event)
+}
+
+#[test]
+fn test_recording_round_trips_through_lines() {
+    let mut state = TinRecordingState::new();
+    state.start(10, None);
+    state.record(10, &key_down(TinKey::A));
+    state.record(12, &TinEvent::MouseMoved(TinPoint::from_coords(1.5, -2.0)));
+    let recording = state.stop().unwrap();
+
+    let mut bytes = Vec::new();
+    recording.write_to(&mut bytes).unwrap();
+    assert_eq!(String::from_utf8(bytes.clone()).unwrap().lines().count(), 2);
+
+    let read = TinEventRecording::read_from(&bytes[..]).unwrap();
+    assert_eq!(read.len(), 2);
+    assert_eq!(read.get_events()[0].frame, 0);
+    assert_eq!(read.last_frame(), 2);
+    match &read.get_events()[0].event {
+        TinEvent::KeyDown(event) => {
+            assert_eq!(event.key, TinKey::A);
+            assert!(event.modifiers.shift());
+        }
+        other => panic!("Expected a key down, got {:?}", other),
+    }
+    match &read.get_events()[1].event {
+        TinEvent::MouseMoved(point) => assert_eq!(point.get_x(), 1.5),
+        other => panic!("Expected a mouse move, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_playback_delivers_events_at_their_frames() {
+    let mut state = TinRecordingState::new();
+    state.start(0, None);
+    state.record(0, &key_down(TinKey::A));
+    state.record(3, &key_down(TinKey::B));
+    let recording = state.stop().unwrap();
+
+    state.play(recording, 100);
+    assert!(state.ignores_live_event(&key_down(TinKey::C)));
+    assert!(!state.ignores_live_event(&TinEvent::WindowCloseRequested));
+    assert_eq!(state.take_due_events(100).len(), 1);
+    assert!(state.take_due_events(102).is_empty());
+    assert_eq!(state.take_due_events(103).len(), 1);
+    assert!(!state.is_playing_back());
+}
@@ -0,0 +1,157 @@
+use std::time::Duration;
+
+use super::assert_approx_eq;
+use crate::context::{get_tin_mut, run_scene_frame, TinContext};
+use crate::draw::DrawCall;
+use crate::frame::TinFrame;
+use crate::point::TPoint;
+use crate::scene::{wipe_panel, TScene, TinSceneManager, TinTransition, TinWipeDirection};
+use crate::window::TinWindowId;
+use crate::{CurrentBackend, TinEvent};
+
+struct EmptyScene;
+
+impl TScene for EmptyScene {
+    fn setup() -> Self {
+        EmptyScene
+    }
+
+    fn update(&mut self) {}
+
+    fn on_event(&mut self, _event: TinEvent) {}
+}
+
+#[test]
+fn test_scene_stack_keeps_its_last_scene() {
+    let mut manager = TinSceneManager::new(Box::new(EmptyScene));
+    manager.push(Box::new(EmptyScene), TinTransition::cut());
+    manager.replace(Box::new(EmptyScene), TinTransition::cut());
+    assert_eq!(manager.depth(), 2);
+    assert!(!manager.is_transitioning());
+
+    manager.pop(TinTransition::crossfade(1.0));
+    assert!(manager.is_transitioning());
+    manager.pop(TinTransition::cut());
+    assert_eq!(manager.depth(), 1);
+    assert!(!manager.is_transitioning());
+}
+
+#[test]
+fn test_wipe_panel_grows_from_its_side() {
+    let left = wipe_panel(TinWipeDirection::FromLeft, 0.0, 0.25, 400.0, 200.0);
+    assert_roughly_eq!(left.get_width(), 100.0);
+    assert_roughly_eq!(left.center.get_x(), -150.0);
+    assert_roughly_eq!(left.get_height(), 200.0);
+
+    let top = wipe_panel(TinWipeDirection::FromTop, 0.5, 1.0, 400.0, 200.0);
+    assert_roughly_eq!(top.get_height(), 100.0);
+    assert_roughly_eq!(top.center.get_y(), -50.0);
+}
+
+/// Remembers which hooks ran, in order.
+#[derive(Default)]
+struct RecordingScene {
+    calls: Vec<&'static str>,
+}
+
+impl TScene for RecordingScene {
+    fn setup() -> Self {
+        Self::default()
+    }
+
+    fn update(&mut self) {
+        self.calls.push("update");
+    }
+
+    fn draw(&mut self) {
+        self.calls.push("draw");
+    }
+
+    fn fixed_update(&mut self, _step: crate::Double) {
+        self.calls.push("fixed_update");
+    }
+
+    fn on_event(&mut self, _event: TinEvent) {}
+
+    fn on_pause(&mut self) {
+        self.calls.push("on_pause");
+    }
+
+    fn on_resume(&mut self) {
+        self.calls.push("on_resume");
+    }
+}
+
+#[test]
+fn test_paused_scenes_only_draw_and_the_clock_stops() {
+    let mut tin = TinContext::<CurrentBackend>::new();
+    tin.prepare(TinFrame::new(800, 600));
+    tin.clock.set_fixed_step(Some(0.25));
+    let mut scene = RecordingScene::default();
+    let mut run_frame = |tin: &mut TinContext<CurrentBackend>| {
+        let frame = tin.begin_scene_frame(Duration::from_millis(250));
+        run_scene_frame(&mut scene, frame);
+        std::mem::take(&mut scene.calls)
+    };
+
+    assert_eq!(run_frame(&mut tin), vec!["fixed_update", "update", "draw"]);
+    assert_eq!(tin.clock.get_time().elapsed, 0.25);
+
+    tin.paused = true;
+    assert_eq!(run_frame(&mut tin), vec!["on_pause", "draw"]);
+    // Windows with their own scene hear about the pause in the same frame, with no fixed updates.
+    let window_frame = tin.window_scene_frame(TinWindowId::new(1));
+    assert!(window_frame.paused && window_frame.pause_changed);
+    assert_eq!(window_frame.fixed_steps, 0);
+    assert_eq!(run_frame(&mut tin), vec!["draw"]);
+    assert_eq!(tin.clock.get_time().elapsed, 0.25);
+
+    tin.paused = false;
+    assert_eq!(run_frame(&mut tin), vec!["on_resume", "fixed_update", "update", "draw"]);
+    assert_eq!(run_frame(&mut tin), vec!["fixed_update", "update", "draw"]);
+    assert_eq!(tin.clock.get_time().elapsed, 0.5);
+}
+
+/// Moves by its number when updated and turns by it when drawn, so its draw calls can be told apart.
+struct MarkingScene(f64);
+
+impl TScene for MarkingScene {
+    fn setup() -> Self {
+        MarkingScene(0.0)
+    }
+
+    fn update(&mut self) {
+        crate::draw::translate(self.0, 0.0);
+    }
+
+    fn draw(&mut self) {
+        crate::draw::rotate(self.0);
+    }
+
+    fn on_event(&mut self, _event: TinEvent) {}
+}
+
+#[test]
+fn test_crossfade_draws_the_incoming_scene_faded_over_the_outgoing_one() {
+    let _context = super::lock_global_context();
+    let mut manager = TinSceneManager::new(Box::new(MarkingScene(1.0)));
+    manager.push(Box::new(MarkingScene(2.0)), TinTransition::crossfade(1.0));
+    get_tin_mut().current_calls().clear();
+    manager.update();
+    manager.draw();
+
+    let calls: Vec<DrawCall> = get_tin_mut().current_calls().drain(..).collect();
+    // No time has passed, so the incoming scene is still fully faded out.
+    assert!(matches!(
+        calls.as_slice(),
+        [
+            DrawCall::Translate(a, _),
+            DrawCall::Rotate(b),
+            DrawCall::ResetState,
+            DrawCall::Opacity(p),
+            DrawCall::Translate(c, _),
+            DrawCall::Rotate(d),
+            DrawCall::Opacity(full),
+        ] if (*a, *b, *c, *d) == (1.0, 1.0, 2.0, 2.0) && *p == 0.0 && *full == 1.0
+    ));
+}
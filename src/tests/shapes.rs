@@ -0,0 +1,43 @@
+use super::assert_approx_eq;
+use crate::shapes::{cubic_point, even_odd_spans, TinPath};
+
+#[test]
+fn test_cubic_point_ends_and_middle() {
+    let curve = |t| cubic_point((0.0, 0.0), (0.0, 1.0), (1.0, 1.0), (1.0, 0.0), t);
+    assert_eq!(curve(0.0), (0.0, 0.0));
+    assert_eq!(curve(1.0), (1.0, 0.0));
+    let (x, y) = curve(0.5);
+    assert_roughly_eq!(x, 0.5);
+    assert_roughly_eq!(y, 0.75);
+}
+
+#[test]
+fn test_even_odd_spans_cut_holes() {
+    let outer = vec![(0.0, 0.0), (10.0, 0.0), (10.0, 10.0), (0.0, 10.0)];
+    let inner = vec![(3.0, 3.0), (7.0, 3.0), (7.0, 7.0), (3.0, 7.0)];
+    assert_eq!(
+        even_odd_spans(&[outer.clone(), inner], 5.0),
+        vec![(0.0, 3.0), (7.0, 10.0)]
+    );
+    assert_eq!(even_odd_spans(&[outer], 5.0), vec![(0.0, 10.0)]);
+}
+
+#[test]
+fn test_path_contours_flatten_and_svg() {
+    let mut path = TinPath::new();
+    path.vertex(0.0, 0.0);
+    path.add_curve(&(3.0, 0.0), &(1.0, 1.0), &(2.0, 1.0));
+    path.begin_contour();
+    path.vertex(5.0, 5.0);
+    path.vertex(6.0, 5.0);
+
+    let flat = path.flatten(4);
+    assert_eq!(flat.len(), 2);
+    assert_eq!(flat[0].len(), 5);
+    assert_roughly_eq!(flat[0][4].x, 3.0);
+    assert_eq!(path.to_svg_path_data(), "M0 0 C1 1 2 1 3 0 Z M5 5 L6 5 Z");
+
+    let moved = path.map_points(|x, y| (x + 1.0, -y));
+    let bounds = moved.bounds().expect("Path should have bounds.");
+    assert_roughly_eq!(bounds.get_width(), 6.0);
+}
@@ -0,0 +1,40 @@
+use super::assert_approx_eq;
+use crate::context::DrawState;
+use crate::space::{TinCoordinateSpace, TinShapeMode, TinSpaceMapping};
+
+#[test]
+fn test_spaces_map_onto_the_window_middle() {
+    let top_left = TinSpaceMapping::new(TinCoordinateSpace::TopLeftPixels, 800.0, 600.0);
+    assert_eq!(top_left.point(0.0, 0.0), (-400.0, 300.0));
+    assert_eq!(top_left.point(800.0, 600.0), (400.0, -300.0));
+    assert_eq!(top_left.inverse_point(-400.0, 300.0), (0.0, 0.0));
+
+    let normalized = TinSpaceMapping::new(TinCoordinateSpace::Normalized, 800.0, 600.0);
+    assert_eq!(normalized.point(1.0, -1.0), (300.0, -300.0));
+    assert_eq!(normalized.length(0.5), 150.0);
+}
+
+#[test]
+fn test_mapped_transform_matches_the_drawing_space() {
+    let mapping = TinSpaceMapping::new(TinCoordinateSpace::TopLeftPixels, 640.0, 480.0);
+    let state = DrawState {
+        rotation: 0.3,
+        scale: 2.0,
+        translation: (50.0, 20.0),
+    };
+    let mapped_state = mapping.state(state);
+    for (x, y) in [(0.0, 0.0), (10.0, 40.0), (-25.0, 7.0)] {
+        let (drawn_x, drawn_y) = state.apply(x, y);
+        let expected = mapping.point(drawn_x, drawn_y);
+        let (mapped_x, mapped_y) = mapping.point(x, y);
+        let actual = mapped_state.apply(mapped_x, mapped_y);
+        assert_roughly_eq!(actual.0, expected.0);
+        assert_roughly_eq!(actual.1, expected.1);
+    }
+}
+
+#[test]
+fn test_shape_modes_find_the_center() {
+    assert_eq!(TinShapeMode::Center.center_of(10.0, 20.0, 4.0, 6.0), (10.0, 20.0));
+    assert_eq!(TinShapeMode::Corner.center_of(10.0, 20.0, 4.0, 6.0), (12.0, 23.0));
+}
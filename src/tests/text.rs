@@ -0,0 +1,307 @@
+use super::assert_approx_eq;
+use crate::{
+    shapes::TinRect,
+    space::{TinCoordinateSpace, TinSpaceMapping},
+    text::{
+        layout_runs, wrap_words, TinFont, TinFontHorizontalAlignment, TinFontMetrics,
+        TinFontVerticalAlignment, TinGlyphBitmap, TinGlyphCache, TinPolylineMeasure,
+        TinTextAlignment, TinTextBitmap, TinTextOnPathOptions,
+    },
+    vector2::TinVector2,
+};
+
+fn char_width(s: &str) -> f32 {
+    s.chars().count() as f32
+}
+
+#[test]
+fn test_wrap_words_fills_lines_greedily() {
+    let lines = wrap_words("the quick brown fox jumps", 10.0, char_width);
+    let words: Vec<String> = lines.iter().map(|(w, _)| w.join(" ")).collect();
+    assert_eq!(words, vec!["the quick", "brown fox", "jumps"]);
+    assert_eq!(
+        lines.iter().map(|(_, end)| *end).collect::<Vec<bool>>(),
+        vec![false, false, true]
+    );
+}
+
+#[test]
+fn test_wrap_words_keeps_paragraphs_and_long_words() {
+    let lines = wrap_words("a\n\nextraordinarily long", 5.0, char_width);
+    let words: Vec<String> = lines.iter().map(|(w, _)| w.join(" ")).collect();
+    assert_eq!(words, vec!["a", "", "extraordinarily", "long"]);
+    assert!(lines[0].1 && lines[1].1);
+}
+
+/// Baselines 3 apart, with the first one 2 below the top of the block.
+const METRICS: TinFontMetrics = TinFontMetrics {
+    ascent: 2.0,
+    descent: -1.0,
+    line_gap: 0.0,
+    line_advance: 3.0,
+};
+
+/// Window-sized mapping for the layout tests, whose boxes fit inside it.
+fn mapping(space: TinCoordinateSpace) -> TinSpaceMapping {
+    TinSpaceMapping::new(space, 100.0, 100.0)
+}
+
+/// Lays out "the quick brown fox jumps" in a 10 x 20 box around the origin, returning each run's (text, x, baseline y).
+fn layout_fox(
+    paragraph: TinTextAlignment,
+    horizontal: TinFontHorizontalAlignment,
+    vertical: TinFontVerticalAlignment,
+) -> Vec<(String, f64, f64)> {
+    let bounds = TinRect::from_dimensions(0.0, 0.0, 10.0, 20.0);
+    let (runs, line_count, overflows) = layout_runs(
+        "the quick brown fox jumps",
+        &bounds,
+        &mapping(TinCoordinateSpace::CenteredPixels),
+        &METRICS,
+        (&paragraph, &horizontal, &vertical),
+        char_width,
+    );
+    assert_eq!(line_count, 3);
+    assert!(!overflows);
+    runs.into_iter()
+        .map(|r| (r.text, r.x, r.baseline_y))
+        .collect()
+}
+
+fn xs(runs: &[(String, f64, f64)]) -> Vec<f64> {
+    runs.iter().map(|(_, x, _)| *x).collect()
+}
+
+#[test]
+fn test_layout_aligns_lines_horizontally() {
+    let top = TinFontVerticalAlignment::Top;
+    let left = layout_fox(
+        TinTextAlignment::Left,
+        TinFontHorizontalAlignment::Center,
+        top.clone(),
+    );
+    assert_eq!(xs(&left), vec![-5.0, -5.0, -5.0]);
+    // Lines are 9, 9 and 5 wide in a box 10 wide.
+    let center = layout_fox(
+        TinTextAlignment::Center,
+        TinFontHorizontalAlignment::Left,
+        top.clone(),
+    );
+    assert_eq!(xs(&center), vec![-4.5, -4.5, -2.5]);
+    let right = layout_fox(
+        TinTextAlignment::Right,
+        TinFontHorizontalAlignment::Left,
+        top.clone(),
+    );
+    assert_eq!(xs(&right), vec![-4.0, -4.0, 0.0]);
+    // Natural follows the font's horizontal alignment.
+    let natural = layout_fox(
+        TinTextAlignment::Natural,
+        TinFontHorizontalAlignment::Right,
+        top,
+    );
+    assert_eq!(natural, right);
+}
+
+#[test]
+fn test_layout_justifies_all_but_the_last_line() {
+    let runs = layout_fox(
+        TinTextAlignment::Justified,
+        TinFontHorizontalAlignment::Left,
+        TinFontVerticalAlignment::Top,
+    );
+    let words: Vec<&str> = runs.iter().map(|(t, _, _)| t.as_str()).collect();
+    assert_eq!(words, vec!["the", "quick", "brown", "fox", "jumps"]);
+    // Each split line is stretched to end at the right edge; the last one is left aligned.
+    assert_eq!(xs(&runs), vec![-5.0, 0.0, -5.0, 2.0, -5.0]);
+    let baselines: Vec<f64> = runs.iter().map(|(_, _, y)| *y).collect();
+    assert_eq!(baselines, vec![8.0, 8.0, 5.0, 5.0, 2.0]);
+}
+
+#[test]
+fn test_layout_places_the_block_vertically() {
+    let first_baseline = |vertical: TinFontVerticalAlignment| {
+        layout_fox(
+            TinTextAlignment::Left,
+            TinFontHorizontalAlignment::Left,
+            vertical,
+        )[0]
+        .2
+    };
+    // The block of three lines is 9 tall, in a box from -10 to 10.
+    assert_approx_eq!(first_baseline(TinFontVerticalAlignment::Top), 8.0);
+    assert_approx_eq!(first_baseline(TinFontVerticalAlignment::Baseline), 8.0);
+    assert_approx_eq!(first_baseline(TinFontVerticalAlignment::Center), 2.5);
+    assert_approx_eq!(first_baseline(TinFontVerticalAlignment::Bottom), -3.0);
+
+    let short = TinRect::from_dimensions(0.0, 0.0, 10.0, 5.0);
+    let (_, _, overflows) = layout_runs(
+        "the quick brown fox jumps",
+        &short,
+        &mapping(TinCoordinateSpace::CenteredPixels),
+        &METRICS,
+        (
+            &TinTextAlignment::Left,
+            &TinFontHorizontalAlignment::Left,
+            &TinFontVerticalAlignment::Top,
+        ),
+        char_width,
+    );
+    assert!(overflows);
+}
+
+#[test]
+fn test_layout_runs_down_the_page_when_y_grows_downwards() {
+    // The same 10 x 20 box, with its top left corner at 20, 30 from the top left of the window.
+    let bounds = TinRect::from_dimensions(25.0, 40.0, 10.0, 20.0);
+    let alignment = (
+        &TinTextAlignment::Left,
+        &TinFontHorizontalAlignment::Left,
+        &TinFontVerticalAlignment::Top,
+    );
+    let (runs, _, overflows) = layout_runs(
+        "the quick brown fox jumps",
+        &bounds,
+        &mapping(TinCoordinateSpace::TopLeftPixels),
+        &METRICS,
+        alignment,
+        char_width,
+    );
+    assert!(!overflows);
+    // The first baseline is the ascent below the top of the box, and each next one further down.
+    let positions: Vec<(f64, f64)> = runs.iter().map(|r| (r.x, r.baseline_y)).collect();
+    assert_eq!(positions, vec![(20.0, 32.0), (20.0, 35.0), (20.0, 38.0)]);
+
+    let (bottom, _, _) = layout_runs(
+        "the quick brown fox jumps",
+        &bounds,
+        &mapping(TinCoordinateSpace::TopLeftPixels),
+        &METRICS,
+        (alignment.0, alignment.1, &TinFontVerticalAlignment::Bottom),
+        char_width,
+    );
+    // The block is 9 tall and ends at the bottom of the box, 50 down.
+    assert_eq!(bottom[2].baseline_y, 49.0);
+}
+
+/// A bitmap four pixels wide: the first row covered at columns 0, 2 and 3, the second row barely covered.
+fn two_row_bitmap() -> TinTextBitmap {
+    TinTextBitmap {
+        width: 4,
+        height: 2,
+        coverage: vec![1.0, 0.2, 0.5, 0.9, 0.1, 0.4, 0.0, 0.3],
+        left: 0.0,
+        top: 0.0,
+    }
+}
+
+#[test]
+fn test_text_bitmap_spans_cover_runs() {
+    let bitmap = two_row_bitmap();
+    assert_eq!(bitmap.spans(0), vec![(0, 1), (2, 4)]);
+    assert!(bitmap.spans(1).is_empty());
+    // Rows past the bottom have nothing in them.
+    assert!(bitmap.spans(2).is_empty());
+    assert_eq!(bitmap.coverage_at(4, 0), 0.0);
+}
+
+#[test]
+fn test_glyph_cache_rasterizes_once_per_key() {
+    let mut cache = TinGlyphCache::new();
+    let mut rasterized = 0;
+    let mut rasterize = || {
+        rasterized += 1;
+        Some(TinGlyphBitmap {
+            left: 0,
+            top: -1,
+            width: 1,
+            height: 1,
+            coverage: vec![1.0],
+        })
+    };
+    let glyph = cache.get_or_rasterize((0, 65, 12.0f32.to_bits()), &mut rasterize);
+    let again = cache.get_or_rasterize((0, 65, 12.0f32.to_bits()), &mut rasterize);
+    assert!(std::sync::Arc::ptr_eq(&glyph.unwrap(), &again.unwrap()));
+    // Another size is another glyph.
+    cache.get_or_rasterize((0, 65, 24.0f32.to_bits()), &mut rasterize);
+    // Glyphs without an outline are remembered too.
+    cache.get_or_rasterize((0, 32, 12.0f32.to_bits()), || None);
+    assert!(cache
+        .get_or_rasterize((0, 32, 12.0f32.to_bits()), &mut rasterize)
+        .is_none());
+    assert_eq!(rasterized, 2);
+    assert_eq!(cache.len(), 3);
+}
+
+#[test]
+fn test_font_from_bad_bytes_is_an_error() {
+    assert!(TinFont::from_bytes(vec![0, 1, 2, 3], 12.0).is_err());
+    assert!(TinFont::from_bytes(Vec::new(), 12.0).is_err());
+    assert!(TinFont::from_file_path(String::from("missing/font.ttf"), 12.0).is_err());
+}
+
+/// Five units up and to the right, then six straight up.
+fn bent_polyline() -> TinPolylineMeasure {
+    TinPolylineMeasure::new(vec![
+        TinVector2::from_xy(0.0, 0.0),
+        TinVector2::from_xy(3.0, 4.0),
+        TinVector2::from_xy(3.0, 10.0),
+    ])
+}
+
+#[test]
+fn test_polyline_measure_finds_points_by_arc_length() {
+    let measure = bent_polyline();
+    assert_approx_eq!(measure.length(), 11.0);
+
+    let (x, y, angle) = measure.point_at(2.5).unwrap();
+    assert_approx_eq!(x, 1.5);
+    assert_approx_eq!(y, 2.0);
+    assert_approx_eq!(angle, 4.0f64.atan2(3.0));
+    // Past the bend, the second segment is used.
+    let (x, y, angle) = measure.point_at(8.0).unwrap();
+    assert_approx_eq!(x, 3.0);
+    assert_approx_eq!(y, 7.0);
+    assert_approx_eq!(angle, std::f64::consts::FRAC_PI_2);
+    // Both ends are on the line.
+    assert_eq!(
+        measure.point_at(0.0).map(|(x, y, _)| (x, y)),
+        Some((0.0, 0.0))
+    );
+    assert_eq!(
+        measure.point_at(11.0).map(|(x, y, _)| (x, y)),
+        Some((3.0, 10.0))
+    );
+}
+
+#[test]
+fn test_polyline_measure_is_none_out_of_range() {
+    let measure = bent_polyline();
+    assert!(measure.point_at(-0.1).is_none());
+    assert!(measure.point_at(11.1).is_none());
+    let single = TinPolylineMeasure::new(vec![TinVector2::from_xy(1.0, 1.0)]);
+    assert_eq!(single.length(), 0.0);
+    assert!(single.point_at(0.0).is_none());
+}
+
+#[test]
+fn test_text_on_path_offsets_follow_the_alignment() {
+    let options = |alignment: TinTextAlignment| TinTextOnPathOptions {
+        start_offset: 2.0,
+        alignment,
+        letter_spacing: 1.0,
+    };
+    // Four glyphs 6 wide, with three gaps of 1, on a path 20 long.
+    let place = |alignment| options(alignment).placement(20.0, 6.0, 4);
+    assert_eq!(place(TinTextAlignment::Left), (2.0, 1.0));
+    assert_eq!(place(TinTextAlignment::Natural), (2.0, 1.0));
+    assert_eq!(place(TinTextAlignment::Center), (7.5, 1.0));
+    // Right alignment measures the offset back from the end.
+    assert_eq!(place(TinTextAlignment::Right), (9.0, 1.0));
+    // Justified text spreads the glyphs to fill the rest of the path.
+    assert_eq!(place(TinTextAlignment::Justified), (2.0, 4.0));
+    assert_eq!(
+        options(TinTextAlignment::Justified).placement(20.0, 2.0, 1),
+        (2.0, 1.0)
+    );
+}
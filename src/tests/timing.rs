@@ -0,0 +1,37 @@
+use super::assert_approx_eq;
+use crate::timing::{TinFrameClock, MAX_FIXED_UPDATES_PER_FRAME};
+
+#[test]
+fn test_clock_adds_up_frame_time() {
+    let mut clock = TinFrameClock::new();
+    assert_eq!(clock.tick(0.5, 1), (0, 0.0));
+    clock.tick(0.25, 2);
+    let time = clock.get_time();
+    assert_roughly_eq!(time.delta, 0.25);
+    assert_roughly_eq!(time.elapsed, 0.75);
+    assert_eq!(time.frame_index, 2);
+    assert_eq!(time.fixed_alpha, 0.0);
+}
+
+#[test]
+fn test_fixed_steps_carry_leftover_time() {
+    let mut clock = TinFrameClock::new();
+    clock.set_fixed_step(Some(0.125));
+    assert_eq!(clock.tick(0.3125, 1).0, 2);
+    assert_roughly_eq!(clock.get_time().fixed_alpha, 0.5);
+    // The half step left over adds up with this frame to make a whole one.
+    assert_eq!(clock.tick(0.0625, 2).0, 1);
+
+    // A long stall runs a limited number of steps instead of catching up all at once.
+    assert_eq!(clock.tick(10.0, 3).0, MAX_FIXED_UPDATES_PER_FRAME);
+    assert!(clock.tick(0.0, 4).0 <= 1);
+}
+
+#[test]
+fn test_clock_remembers_the_last_steps() {
+    let mut clock = TinFrameClock::new();
+    clock.set_fixed_step(Some(0.125));
+    let steps = clock.tick(0.25, 1);
+    assert_eq!(clock.get_last_steps(), steps);
+    assert_eq!(steps.0, 2);
+}
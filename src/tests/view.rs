@@ -0,0 +1,45 @@
+use crate::context::TinContext;
+use crate::event::TinEvent;
+use crate::frame::TinFrame;
+use crate::view::{TView, TinView, TinWindowPosition};
+use crate::CurrentBackend;
+
+#[test]
+fn test_window_positions_resolve_on_their_monitor() {
+    let monitor = ((1920, 0), (2560, 1440));
+    let resolve = |position: TinWindowPosition| position.resolve(monitor.0, monitor.1, (800, 600));
+    assert_eq!(resolve(TinWindowPosition::Default), None);
+    assert_eq!(resolve(TinWindowPosition::At(10, 20)), Some((10, 20)));
+    assert_eq!(resolve(TinWindowPosition::TopLeft), Some((1920, 0)));
+    assert_eq!(resolve(TinWindowPosition::Centered), Some((1920 + 880, 420)));
+}
+
+#[test]
+fn test_view_settings_chain() {
+    let mut view = TinView::from_dimensions("Piece", 640, 480)
+        .resizable(false)
+        .cursor_visible(false);
+    view.move_window_to_center();
+    assert_eq!(view.get_title(), "Piece");
+    assert!(!view.is_resizable());
+    assert!(!view.is_cursor_visible());
+    assert!(view.has_decorations());
+    assert_eq!(view.get_position(), TinWindowPosition::Centered);
+}
+
+#[test]
+fn test_resize_events_update_the_context_size() {
+    let mut tin = TinContext::<CurrentBackend>::new();
+    tin.prepare(TinFrame::new(800, 600));
+    tin.handle_event(&TinEvent::WindowResized(1024, 768));
+    assert_eq!(tin.width, 1024.0);
+    assert_eq!(tin.mid_y, 384.0);
+    assert_eq!(tin.view.get_frame().get_width(), 1024);
+
+    // The next frame keeps the new size instead of the one the app started with.
+    tin.prepare_for_update();
+    assert_eq!(tin.size, [1024.0, 768.0]);
+
+    tin.handle_event(&TinEvent::WindowPixelDensityChanged(2.0));
+    assert_eq!(tin.pixel_density, 2.0);
+}
@@ -0,0 +1,28 @@
+use crate::point::TPoint;
+use crate::shapes::TinRect;
+use crate::space::{TinCoordinateSpace, TinSpaceMapping};
+use crate::viewport::{grid_rects, rect_contains};
+
+#[test]
+fn test_grid_cells_read_from_the_top_left() {
+    let rects = grid_rects(320.0, 200.0, 3, 2, 10.0);
+    assert_eq!(rects.len(), 6);
+    assert_eq!(rects[0].get_width(), 100.0);
+    assert_eq!(rects[0].get_height(), 95.0);
+    assert_eq!((rects[0].center.get_x(), rects[0].center.get_y()), (-110.0, 52.5));
+    assert_eq!((rects[5].center.get_x(), rects[5].center.get_y()), (110.0, -52.5));
+    assert!(grid_rects(320.0, 200.0, 0, 2, 10.0).is_empty());
+}
+
+#[test]
+fn test_viewport_spaces_start_at_the_viewport() {
+    let cell = TinRect::from_dimensions(-110.0, 52.5, 100.0, 95.0);
+    let top_left = TinSpaceMapping::within(TinCoordinateSpace::TopLeftPixels, &cell);
+    assert_eq!(top_left.point(0.0, 0.0), (-160.0, 100.0));
+    assert_eq!(top_left.point(100.0, 95.0), (-60.0, 5.0));
+
+    let centered = TinSpaceMapping::within(TinCoordinateSpace::CenteredPixels, &cell);
+    assert_eq!(centered.point(0.0, 0.0), (-110.0, 52.5));
+    assert!(rect_contains(&cell, -160.0, 100.0));
+    assert!(!rect_contains(&cell, -50.0, 52.5));
+}
@@ -0,0 +1,58 @@
+use crate::color::{TColor, TinColor};
+use crate::context::TinContext;
+use crate::draw::DrawCall;
+use crate::frame::TinFrame;
+use crate::scene::TScene;
+use crate::view::{TView, TinView};
+use crate::window::{TinWindowId, TinWindowScene};
+use crate::{CurrentBackend, Tin, TinEvent};
+
+struct EmptyScene;
+
+impl TScene for EmptyScene {
+    fn setup() -> Self {
+        EmptyScene
+    }
+
+    fn update(&mut self) {}
+
+    fn on_event(&mut self, _event: TinEvent) {}
+}
+
+#[test]
+fn test_windows_are_numbered_after_the_main_one() {
+    let view = TinView::new("Output", TinFrame::new(320, 240));
+    let app = Tin::<EmptyScene>::app()
+        .window(view.clone())
+        .window_with_scene::<EmptyScene>(view);
+    let ids: Vec<TinWindowId> = app.windows.iter().map(|window| window.id).collect();
+    assert_eq!(ids, vec![TinWindowId::new(1), TinWindowId::new(2)]);
+    assert!(ids.iter().all(|id| !id.is_main()));
+    assert!(TinWindowId::MAIN.is_main());
+    assert!(matches!(app.windows[0].scene, TinWindowScene::Shared));
+    assert!(matches!(app.windows[1].scene, TinWindowScene::Own(_)));
+}
+
+#[test]
+fn test_each_window_draws_only_its_own_calls() {
+    let mut tin = TinContext::<CurrentBackend>::new();
+    tin.prepare(TinFrame::new(800, 600));
+    let output = TinWindowId::new(1);
+    let red = TinColor::from_rgba(1.0, 0.0, 0.0, 1.0);
+
+    tin.current_calls().push_back(DrawCall::Rotate(1.0));
+    tin.current_calls().push_back(DrawCall::Rotate(2.0));
+    tin.use_window(output);
+    tin.current_calls().push_back(DrawCall::Background(red));
+    assert_eq!(tin.current_calls().len(), 1);
+
+    tin.process_draw_calls();
+    assert_eq!(tin.get_background_color().get_red(), 1.0);
+    assert!(tin.current_calls().is_empty());
+    // The main window's calls wait for it to be drawn.
+    tin.use_window(TinWindowId::MAIN);
+    assert!(matches!(
+        tin.current_calls().make_contiguous(),
+        [DrawCall::Rotate(a), DrawCall::Rotate(b)] if (*a, *b) == (1.0, 2.0)
+    ));
+}
@@ -1,10 +1,27 @@
 extern crate font_kit;
+mod layout;
+pub use layout::*;
+mod on_path;
+pub use on_path::*;
+mod outline;
+
+use ab_glyph::{Font, FontVec, GlyphId, PxScale, ScaleFont};
 use font_kit::{
-    family_name::FamilyName, loaders::freetype::Font, properties::Properties,
+    family_name::FamilyName, handle::Handle, properties::Properties,
     sources::fs::FsSource as FontSource,
 };
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+};
 
-use crate::{draw::text, point::TinPoint, Double, Float};
+use crate::{color::TinColor, draw::text, point::TinPoint, Double, Float, ULong};
+
+/// Source of the ids used to tell fonts apart in glyph caches.
+static NEXT_FONT_ID: AtomicU64 = AtomicU64::new(0);
 
 #[derive(Debug, Clone)]
 pub enum TinFontHorizontalAlignment {
@@ -32,7 +49,11 @@ pub enum TinTextAlignment {
 
 #[derive(Debug, Clone)]
 pub struct TinFont {
-    //pub(crate) font: Font, TODO: Implement generic means of selecting font
+    /// The parsed font, which also holds the file's data for backends that load fonts with their own libraries.
+    pub(crate) font: Arc<FontVec>,
+    /// Which font of a font collection `font` is.
+    pub(crate) font_index: u32,
+    pub(crate) id: ULong,
     pub(crate) font_size: Float,
     pub line_height_multiple: Float,
 
@@ -43,15 +64,36 @@ pub struct TinFont {
 }
 
 impl TinFont {
+    /// Load the installed font that best matches a family name, such as `FamilyName::SansSerif`.
     pub fn new(family_name: FamilyName, size: Float) -> Result<Self, ()> {
-        let font = FontSource::new()
+        let handle = FontSource::new()
             .select_best_match(&[family_name], &Properties::new())
-            .unwrap()
-            .load()
-            .expect("Unable to initialize font");
+            .map_err(|_| ())?;
+        let (bytes, font_index) = match handle {
+            Handle::Path { path, font_index } => (std::fs::read(path).map_err(|_| ())?, font_index),
+            Handle::Memory { bytes, font_index } => ((*bytes).clone(), font_index),
+        };
+        Self::from_bytes_and_index(bytes, font_index, size)
+    }
 
+    /// Load a TrueType or OpenType font file.
+    pub fn from_file_path(file_path: String, size: Float) -> Result<Self, ()> {
+        let bytes = std::fs::read(file_path).map_err(|_| ())?;
+        Self::from_bytes(bytes, size)
+    }
+
+    /// Load a font from TrueType or OpenType data, such as the output of `include_bytes!`.
+    pub fn from_bytes(bytes: Vec<u8>, size: Float) -> Result<Self, ()> {
+        Self::from_bytes_and_index(bytes, 0, size)
+    }
+
+    /// Load one font out of a font collection.
+    pub fn from_bytes_and_index(bytes: Vec<u8>, font_index: u32, size: Float) -> Result<Self, ()> {
+        let font = FontVec::try_from_vec_and_index(bytes, font_index).map_err(|_| ())?;
         Ok(Self {
-            // font,
+            font: Arc::new(font),
+            font_index,
+            id: NEXT_FONT_ID.fetch_add(1, Ordering::Relaxed),
             font_size: size,
             line_height_multiple: 1.0,
             horizontal_alignment: TinFontHorizontalAlignment::Center,
@@ -61,14 +103,218 @@ impl TinFont {
         })
     }
 
-    /** Returns the amount of space needed to draw the text with the given font. */
-    pub fn size(&self, _message: String) -> [f32; 2] {
-        todo!("Incomplete: Tin Font support not implemented");
+    pub fn get_font_size(&self) -> Float {
+        self.font_size
+    }
+
+    pub fn set_font_size(&mut self, size: Float) {
+        self.font_size = size.max(0.0);
     }
 
     pub fn draw(&self, message: &String, x: Double, y: Double) {
         text(message, &self, x, y)
     }
+
+    pub(crate) fn get_px_scale(&self) -> PxScale {
+        PxScale::from(self.font_size)
+    }
+
+    /// Distance from the baseline to the top of the tallest glyphs, at the current size.
+    pub(crate) fn ascent(&self) -> Float {
+        self.font.as_scaled(self.get_px_scale()).ascent()
+    }
+
+    /// Each glyph in a single line with its pen position, and the total advance width.
+    pub(crate) fn glyph_positions(&self, message: &str) -> (Vec<(GlyphId, Float)>, Float) {
+        let scaled = self.font.as_scaled(self.get_px_scale());
+        let mut glyphs = Vec::with_capacity(message.len());
+        let mut pen_x: Float = 0.0;
+        let mut previous: Option<GlyphId> = None;
+        for c in message.chars().filter(|c| !c.is_control()) {
+            let id = scaled.glyph_id(c);
+            if let Some(p) = previous {
+                pen_x += scaled.kern(p, id) + self.kerning;
+            }
+            glyphs.push((id, pen_x));
+            pen_x += scaled.h_advance(id);
+            previous = Some(id);
+        }
+        (glyphs, pen_x)
+    }
+
+    /**
+     Offset from the anchor point to the start of a line's baseline, in drawing units with y up,
+     following the font's horizontal and vertical alignment.
+    */
+    pub(crate) fn anchor_offset(&self, line_width: Float) -> (Double, Double) {
+        let scaled = self.font.as_scaled(self.get_px_scale());
+        let dx = match self.horizontal_alignment {
+            TinFontHorizontalAlignment::Left => 0.0,
+            TinFontHorizontalAlignment::Center => -line_width / 2.0,
+            TinFontHorizontalAlignment::Right => -line_width,
+        };
+        // Descent is negative, since it is measured upwards from the baseline.
+        let dy = match self.vertical_alignment {
+            TinFontVerticalAlignment::Baseline => 0.0,
+            TinFontVerticalAlignment::Top => -scaled.ascent(),
+            TinFontVerticalAlignment::Bottom => -scaled.descent(),
+            TinFontVerticalAlignment::Center => -(scaled.ascent() + scaled.descent()) / 2.0,
+        };
+        (dx as Double, dy as Double)
+    }
+
+    fn rasterize_glyph(&self, id: GlyphId) -> Option<TinGlyphBitmap> {
+        let glyph = id.with_scale_and_position(self.get_px_scale(), ab_glyph::point(0.0, 0.0));
+        let outlined = self.font.outline_glyph(glyph)?;
+        let bounds = outlined.px_bounds();
+        let (width, height) = (bounds.width() as usize, bounds.height() as usize);
+        let mut coverage = vec![0.0; width * height];
+        outlined.draw(|x, y, c| {
+            if let Some(value) = coverage.get_mut(y as usize * width + x as usize) {
+                *value = c;
+            }
+        });
+        Some(TinGlyphBitmap {
+            left: bounds.min.x as i32,
+            top: bounds.min.y as i32,
+            width,
+            height,
+            coverage,
+        })
+    }
+
+    /// Rasterize a single line of text into a coverage bitmap positioned around the anchor point.
+    pub(crate) fn rasterize(&self, message: &str, cache: &mut TinGlyphCache) -> TinTextBitmap {
+        let (positions, line_width) = self.glyph_positions(message);
+        let placed: Vec<(i32, Arc<TinGlyphBitmap>)> = positions
+            .into_iter()
+            .filter_map(|(id, pen_x)| cache.get(self, id).map(|g| (pen_x.round() as i32, g)))
+            .collect();
+        let (dx, dy) = self.anchor_offset(line_width);
+        if placed.is_empty() {
+            return TinTextBitmap::empty(dx, dy);
+        }
+
+        // Glyph bitmaps use pixel rows that grow downwards from the baseline.
+        let min_x = placed.iter().map(|(x, g)| x + g.left).min().unwrap_or(0);
+        let max_x = placed.iter().map(|(x, g)| x + g.left + g.width as i32).max().unwrap_or(0);
+        let min_y = placed.iter().map(|(_, g)| g.top).min().unwrap_or(0);
+        let max_y = placed.iter().map(|(_, g)| g.top + g.height as i32).max().unwrap_or(0);
+        let (width, height) = ((max_x - min_x) as usize, (max_y - min_y) as usize);
+
+        let mut coverage = vec![0.0; width * height];
+        for (x, glyph) in &placed {
+            let column_offset = (x + glyph.left - min_x) as usize;
+            let row_offset = (glyph.top - min_y) as usize;
+            for row in 0..glyph.height {
+                for column in 0..glyph.width {
+                    let target = &mut coverage[(row + row_offset) * width + column + column_offset];
+                    *target = (*target + glyph.coverage[row * glyph.width + column]).min(1.0);
+                }
+            }
+        }
+        TinTextBitmap {
+            width,
+            height,
+            coverage,
+            left: dx + min_x as Double,
+            top: dy - min_y as Double,
+        }
+    }
+}
+
+/// Coverage of one glyph rendered at the origin, with its offset from the pen position in pixels (y down).
+#[derive(Debug)]
+pub(crate) struct TinGlyphBitmap {
+    pub left: i32,
+    pub top: i32,
+    pub width: usize,
+    pub height: usize,
+    pub coverage: Vec<Float>,
+}
+
+/// A font's id, a glyph id and the font size's bits.
+pub(crate) type TinGlyphKey = (ULong, u16, u32);
+
+/// Rasterized glyphs, keyed by font, glyph and size, so each is only rasterized once.
+#[derive(Debug, Default)]
+pub(crate) struct TinGlyphCache {
+    glyphs: HashMap<TinGlyphKey, Option<Arc<TinGlyphBitmap>>>,
+}
+
+impl TinGlyphCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get(&mut self, font: &TinFont, id: GlyphId) -> Option<Arc<TinGlyphBitmap>> {
+        let key = (font.id, id.0, font.font_size.to_bits());
+        self.get_or_rasterize(key, || font.rasterize_glyph(id))
+    }
+
+    /// The glyph cached for a key, rasterized on a miss. Glyphs without an outline, such as spaces, are cached as `None`.
+    pub fn get_or_rasterize(
+        &mut self,
+        key: TinGlyphKey,
+        rasterize: impl FnOnce() -> Option<TinGlyphBitmap>,
+    ) -> Option<Arc<TinGlyphBitmap>> {
+        self.glyphs
+            .entry(key)
+            .or_insert_with(|| rasterize().map(Arc::new))
+            .clone()
+    }
+
+    pub fn len(&self) -> usize {
+        self.glyphs.len()
+    }
+}
+
+/// Coverage of a line of text, from 0.0 to 1.0 per pixel, stored row by row from the top.
+#[derive(Debug)]
+pub(crate) struct TinTextBitmap {
+    pub width: usize,
+    pub height: usize,
+    pub coverage: Vec<Float>,
+    /// Offset of the top left corner from the anchor point, in drawing units with y up.
+    pub left: Double,
+    pub top: Double,
+}
+
+impl TinTextBitmap {
+    fn empty(left: Double, top: Double) -> Self {
+        Self {
+            width: 0,
+            height: 0,
+            coverage: Vec::new(),
+            left,
+            top,
+        }
+    }
+
+    pub fn coverage_at(&self, column: usize, row: usize) -> Float {
+        if column >= self.width || row >= self.height {
+            return 0.0;
+        }
+        self.coverage[row * self.width + column]
+    }
+
+    /// Runs of mostly covered pixels in a row, as (first column, column after the last).
+    pub fn spans(&self, row: usize) -> Vec<(usize, usize)> {
+        let mut spans = Vec::new();
+        let mut start = None;
+        for column in 0..=self.width {
+            let covered = self.coverage_at(column, row) >= 0.5;
+            match (start, covered) {
+                (None, true) => start = Some(column),
+                (Some(s), false) => {
+                    spans.push((s, column));
+                    start = None;
+                }
+                _ => {}
+            }
+        }
+        spans
+    }
 }
 
 pub(crate) trait TextRenderer {
@@ -77,6 +323,7 @@ pub(crate) trait TextRenderer {
         message: &String,
         font: &TinFont,
         center: TinPoint,
+        color: TinColor,
         state: crate::context::DrawState,
     ); // TODO: Implement TFont
 }
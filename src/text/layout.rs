@@ -0,0 +1,264 @@
+use ab_glyph::{Font, ScaleFont};
+
+use crate::{
+    context::get_tin,
+    draw::text,
+    point::TPoint,
+    shapes::TinRect,
+    space::TinSpaceMapping,
+    text::{TinFont, TinFontHorizontalAlignment, TinFontVerticalAlignment, TinTextAlignment},
+    Double, Float,
+};
+
+/// Vertical metrics of a font at its current size, in drawing units.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TinFontMetrics {
+    /// Distance from the baseline up to the top of the tallest glyphs.
+    pub ascent: Float,
+    /// Distance from the baseline to the bottom of the lowest glyphs. This is negative.
+    pub descent: Float,
+    /// Extra space the font asks for between lines.
+    pub line_gap: Float,
+    /// Distance between consecutive baselines, including `line_height_multiple`.
+    pub line_advance: Float,
+}
+
+/// The space needed to draw a piece of text, with the font metrics used to measure it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TinTextSize {
+    /// Advance width of the widest line.
+    pub width: Float,
+    /// From the top of the first line to the bottom of the last.
+    pub height: Float,
+    pub line_count: usize,
+    pub metrics: TinFontMetrics,
+}
+
+/// A piece of text placed by a layout, starting at a point on its baseline.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TinTextRun {
+    pub text: String,
+    pub x: Double,
+    pub baseline_y: Double,
+}
+
+/// Text broken into lines and positioned inside a rectangle, ready to draw.
+#[derive(Debug, Clone)]
+pub struct TinTextLayout {
+    pub runs: Vec<TinTextRun>,
+    pub line_count: usize,
+    /// True when the lines are taller than the rectangle.
+    pub overflows: bool,
+    pub(crate) font: TinFont,
+}
+
+impl TinTextLayout {
+    /// Queue draw calls for every run, with the current fill color and transform.
+    pub fn draw(&self) {
+        for run in &self.runs {
+            text(&run.text, &self.font, run.x, run.baseline_y);
+        }
+    }
+}
+
+/**
+ Greedy word wrap. Paragraphs are split on newlines and words on whitespace.
+ A word wider than `max_width` gets a line to itself rather than being broken.
+
+ - Returns: (Vec<(Vec<String>, bool)>) The words of each line, and whether the line ends its paragraph.
+*/
+pub(crate) fn wrap_words(
+    message: &str,
+    max_width: Float,
+    measure: impl Fn(&str) -> Float,
+) -> Vec<(Vec<String>, bool)> {
+    let mut lines = Vec::new();
+    for paragraph in message.split('\n') {
+        let mut line: Vec<String> = Vec::new();
+        for word in paragraph.split_whitespace() {
+            if !line.is_empty() {
+                let candidate = format!("{} {}", line.join(" "), word);
+                if measure(&candidate) > max_width {
+                    lines.push((std::mem::take(&mut line), false));
+                }
+            }
+            line.push(String::from(word));
+        }
+        lines.push((line, true));
+    }
+    lines
+}
+
+/**
+ Wraps and positions lines of text inside a rectangle. See `TinFont::layout`.
+ Glyphs are not scaled by the coordinate space and y may grow downwards in it, so the lines are laid out
+ in backend coordinates, where y grows upwards, and the runs are mapped back into the drawing space.
+
+ - Parameter bounds: (TinRect) The rectangle, in the drawing space.
+ - Parameter mapping: (TinSpaceMapping) Maps the drawing space onto backend coordinates.
+ - Parameter alignment: The paragraph, horizontal and vertical alignment of the font.
+ - Parameter measure: Advance width of a single line of text.
+
+ - Returns: (Vec<TinTextRun>, usize, bool) The runs, the number of lines, and whether they overflow the rectangle.
+*/
+pub(crate) fn layout_runs(
+    message: &str,
+    bounds: &TinRect,
+    mapping: &TinSpaceMapping,
+    metrics: &TinFontMetrics,
+    alignment: (
+        &TinTextAlignment,
+        &TinFontHorizontalAlignment,
+        &TinFontVerticalAlignment,
+    ),
+    measure: impl Fn(&str) -> Float,
+) -> (Vec<TinTextRun>, usize, bool) {
+    let (paragraph_alignment, horizontal_alignment, vertical_alignment) = alignment;
+    let area = mapping.rect(bounds);
+    let box_width = area.get_width() as Float;
+    let left = area.center.get_x() - area.get_width() / 2.0;
+    let top = area.center.get_y() + area.get_height() / 2.0;
+
+    let lines = wrap_words(message, box_width, &measure);
+    let block_height =
+        metrics.ascent - metrics.descent + (lines.len() - 1) as Float * metrics.line_advance;
+    let block_top = match vertical_alignment {
+        TinFontVerticalAlignment::Top | TinFontVerticalAlignment::Baseline => top,
+        TinFontVerticalAlignment::Center => area.center.get_y() + block_height as Double / 2.0,
+        TinFontVerticalAlignment::Bottom => top - area.get_height() + block_height as Double,
+    };
+
+    let space_width = measure(" ");
+    let mut runs = Vec::new();
+    for (index, (words, ends_paragraph)) in lines.iter().enumerate() {
+        let baseline_y = block_top
+            - metrics.ascent as Double
+            - (index as Float * metrics.line_advance) as Double;
+        let line = words.join(" ");
+        let line_width = measure(&line);
+        let free = (box_width - line_width) as Double;
+
+        let alignment = match (paragraph_alignment, horizontal_alignment) {
+            (TinTextAlignment::Natural, TinFontHorizontalAlignment::Left) => TinTextAlignment::Left,
+            (TinTextAlignment::Natural, TinFontHorizontalAlignment::Center) => {
+                TinTextAlignment::Center
+            }
+            (TinTextAlignment::Natural, TinFontHorizontalAlignment::Right) => {
+                TinTextAlignment::Right
+            }
+            (a, _) => a.clone(),
+        };
+        match alignment {
+            TinTextAlignment::Justified if !ends_paragraph && words.len() > 1 => {
+                let word_widths: Vec<Float> = words.iter().map(|w| measure(w)).collect();
+                let gap =
+                    (box_width - word_widths.iter().sum::<Float>()) / (words.len() - 1) as Float;
+                let mut x = left;
+                for (word, width) in words.iter().zip(word_widths) {
+                    runs.push(TinTextRun {
+                        text: word.clone(),
+                        x,
+                        baseline_y,
+                    });
+                    x += (width + gap.max(space_width)) as Double;
+                }
+                continue;
+            }
+            TinTextAlignment::Center => runs.push(TinTextRun {
+                text: line,
+                x: left + free / 2.0,
+                baseline_y,
+            }),
+            TinTextAlignment::Right => runs.push(TinTextRun {
+                text: line,
+                x: left + free,
+                baseline_y,
+            }),
+            _ => runs.push(TinTextRun {
+                text: line,
+                x: left,
+                baseline_y,
+            }),
+        }
+    }
+
+    for run in runs.iter_mut() {
+        let (x, baseline_y) = mapping.inverse_point(run.x, run.baseline_y);
+        run.x = x;
+        run.baseline_y = baseline_y;
+    }
+    let overflows = block_height as Double > area.get_height();
+    (runs, lines.len(), overflows)
+}
+
+impl TinFont {
+    /// Vertical metrics at the current size.
+    pub fn metrics(&self) -> TinFontMetrics {
+        let scaled = self.font.as_scaled(self.get_px_scale());
+        TinFontMetrics {
+            ascent: scaled.ascent(),
+            descent: scaled.descent(),
+            line_gap: scaled.line_gap(),
+            line_advance: (scaled.ascent() - scaled.descent() + scaled.line_gap())
+                * self.line_height_multiple,
+        }
+    }
+
+    /** Returns the amount of space needed to draw the text with the given font. */
+    pub fn size(&self, message: String) -> TinTextSize {
+        let metrics = self.metrics();
+        let lines: Vec<&str> = message.split('\n').collect();
+        let width = lines
+            .iter()
+            .map(|line| self.glyph_positions(line).1)
+            .fold(0.0, Float::max);
+        let height =
+            metrics.ascent - metrics.descent + (lines.len() - 1) as Float * metrics.line_advance;
+        TinTextSize {
+            width,
+            height,
+            line_count: lines.len(),
+            metrics,
+        }
+    }
+
+    /// Advance width of a single line of text.
+    pub fn text_width(&self, message: &str) -> Float {
+        self.glyph_positions(message).1
+    }
+
+    /**
+     Word-wraps text to fit the width of a rectangle and positions the lines inside it.
+
+     Lines are placed with `paragraph_alignment`, where `Justified` stretches the space between
+     words on every line but the last of a paragraph, and `Natural` follows `horizontal_alignment`.
+     The block of lines is placed with `vertical_alignment`; `Baseline` is treated like `Top`.
+    */
+    pub fn layout(&self, message: &str, bounds: &TinRect) -> TinTextLayout {
+        let mapping = get_tin().drawing_mapping();
+        let (runs, line_count, overflows) = layout_runs(
+            message,
+            bounds,
+            &mapping,
+            &self.metrics(),
+            (
+                &self.paragraph_alignment,
+                &self.horizontal_alignment,
+                &self.vertical_alignment,
+            ),
+            |s| self.text_width(s),
+        );
+        // Runs are already positioned, so they are drawn from their baseline start.
+        TinTextLayout {
+            runs,
+            line_count,
+            overflows,
+            font: self.baseline_font(),
+        }
+    }
+
+    /// Word-wrap and draw text inside a rectangle. See `layout`.
+    pub fn draw_in_rect(&self, message: &str, bounds: &TinRect) {
+        self.layout(message, bounds).draw();
+    }
+}
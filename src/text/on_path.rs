@@ -0,0 +1,220 @@
+use ab_glyph::{Font, ScaleFont};
+
+use crate::{
+    draw::{rotate, text, translate},
+    shapes::{TinPath, PATH_CURVE_STEPS},
+    text::{TinFont, TinFontHorizontalAlignment, TinFontVerticalAlignment, TinTextAlignment},
+    vector2::TinVector2,
+    Double,
+};
+
+/// How text is fitted onto a path.
+#[derive(Debug, Clone)]
+pub struct TinTextOnPathOptions {
+    /// Distance along the path before the text starts, or from the end for `Right` alignment.
+    pub start_offset: Double,
+    /**
+     Where the text sits along the path. `Center` centers it on the path's midpoint (moved by `start_offset`),
+     `Justified` spreads the glyphs out so the text runs to the end of the path, and `Natural` is `Left`.
+    */
+    pub alignment: TinTextAlignment,
+    /// Extra space added between glyphs, in drawing units.
+    pub letter_spacing: Double,
+}
+
+impl Default for TinTextOnPathOptions {
+    fn default() -> Self {
+        Self {
+            start_offset: 0.0,
+            alignment: TinTextAlignment::Left,
+            letter_spacing: 0.0,
+        }
+    }
+}
+
+impl TinTextOnPathOptions {
+    /**
+     Where the text starts along a path and the space added between its glyphs.
+
+     - Parameter length: (Double) Length of the path.
+     - Parameter line_width: (Double) Advance width of the text, without letter spacing.
+     - Parameter glyph_count: (usize) Number of glyphs in the text.
+
+     - Returns: (Double, Double) The distance along the path to the first glyph, and the spacing between glyphs.
+    */
+    pub(crate) fn placement(
+        &self,
+        length: Double,
+        line_width: Double,
+        glyph_count: usize,
+    ) -> (Double, Double) {
+        let gaps = glyph_count.saturating_sub(1) as Double;
+        let natural_width = line_width + gaps * self.letter_spacing;
+        match self.alignment {
+            TinTextAlignment::Left | TinTextAlignment::Natural => {
+                (self.start_offset, self.letter_spacing)
+            }
+            TinTextAlignment::Center => (
+                (length - natural_width) / 2.0 + self.start_offset,
+                self.letter_spacing,
+            ),
+            TinTextAlignment::Right => (
+                length - natural_width - self.start_offset,
+                self.letter_spacing,
+            ),
+            TinTextAlignment::Justified if gaps > 0.0 => (
+                self.start_offset,
+                (length - self.start_offset - line_width) / gaps,
+            ),
+            TinTextAlignment::Justified => (self.start_offset, self.letter_spacing),
+        }
+    }
+}
+
+/// One glyph of text placed on a path, with its baseline origin and rotation in radians.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TinGlyphOnPath {
+    pub character: char,
+    pub x: Double,
+    pub y: Double,
+    pub angle: Double,
+}
+
+/// A polyline with the distance along it to each point.
+pub(crate) struct TinPolylineMeasure {
+    points: Vec<TinVector2>,
+    distances: Vec<Double>,
+}
+
+impl TinPolylineMeasure {
+    pub fn new(points: Vec<TinVector2>) -> Self {
+        let mut distances = Vec::with_capacity(points.len());
+        let mut total = 0.0;
+        for (i, p) in points.iter().enumerate() {
+            if i > 0 {
+                let previous = &points[i - 1];
+                total += (p.x - previous.x).hypot(p.y - previous.y);
+            }
+            distances.push(total);
+        }
+        Self { points, distances }
+    }
+
+    pub fn length(&self) -> Double {
+        self.distances.last().copied().unwrap_or(0.0)
+    }
+
+    /// Point and tangent angle at a distance along the polyline, or `None` past either end.
+    pub fn point_at(&self, distance: Double) -> Option<(Double, Double, Double)> {
+        if self.points.len() < 2 || distance < 0.0 || distance > self.length() {
+            return None;
+        }
+        let index = self
+            .distances
+            .iter()
+            .position(|d| *d >= distance)
+            .unwrap_or(self.points.len() - 1)
+            .max(1);
+        let (a, b) = (&self.points[index - 1], &self.points[index]);
+        let segment = self.distances[index] - self.distances[index - 1];
+        let t = if segment > 0.0 {
+            (distance - self.distances[index - 1]) / segment
+        } else {
+            0.0
+        };
+        Some((
+            a.x + (b.x - a.x) * t,
+            a.y + (b.y - a.y) * t,
+            (b.y - a.y).atan2(b.x - a.x),
+        ))
+    }
+}
+
+impl TinFont {
+    /**
+     Places each glyph of a single line of text along the first contour of a path, rotated to follow it.
+     The contour is followed as an open line from its first point to its last, so repeat the first point
+     at the end to go all the way around a closed shape. Glyphs that would fall off the path are left out.
+     The glyphs sit on their baseline, moved off the path by the font's `vertical_alignment`.
+    */
+    pub fn glyphs_on_path(
+        &self,
+        message: &str,
+        path: &TinPath,
+        options: &TinTextOnPathOptions,
+    ) -> Vec<TinGlyphOnPath> {
+        let measure = match path.flatten(PATH_CURVE_STEPS).into_iter().next() {
+            Some(points) => TinPolylineMeasure::new(points),
+            None => return Vec::new(),
+        };
+        let scaled = self.font.as_scaled(self.get_px_scale());
+        let characters: Vec<char> = message.chars().filter(|c| !c.is_control()).collect();
+        let (positions, line_width) = self.glyph_positions(message);
+        if positions.is_empty() {
+            return Vec::new();
+        }
+
+        let (start, spacing) =
+            options.placement(measure.length(), line_width as Double, positions.len());
+        let (_, normal_offset) = self.anchor_offset(0.0);
+
+        // Each glyph is placed by the point under its middle, so it follows the curve evenly.
+        characters
+            .into_iter()
+            .zip(positions)
+            .enumerate()
+            .filter_map(|(index, (character, (id, pen_x)))| {
+                let half_advance = scaled.h_advance(id) as Double / 2.0;
+                let middle = start + pen_x as Double + index as Double * spacing + half_advance;
+                let (x, y, angle) = measure.point_at(middle)?;
+                let (sin, cos) = angle.sin_cos();
+                Some(TinGlyphOnPath {
+                    character,
+                    x: x - half_advance * cos - normal_offset * sin,
+                    y: y - half_advance * sin + normal_offset * cos,
+                    angle,
+                })
+            })
+            .collect()
+    }
+
+    /// Draws text along a path with the current fill color and transform. See `glyphs_on_path`.
+    pub fn draw_on_path(&self, message: &str, path: &TinPath, options: &TinTextOnPathOptions) {
+        let font = self.baseline_font();
+        for glyph in self.glyphs_on_path(message, path, options) {
+            // The transform state adds up, so each glyph's move and turn are undone after it is drawn.
+            translate(glyph.x, glyph.y);
+            rotate(glyph.angle);
+            text(&glyph.character.to_string(), &font, 0.0, 0.0);
+            rotate(-glyph.angle);
+            translate(-glyph.x, -glyph.y);
+        }
+    }
+
+    /// Outlines of text along a path, as one path. See `glyphs_on_path`.
+    pub fn outline_on_path(
+        &self,
+        message: &str,
+        path: &TinPath,
+        options: &TinTextOnPathOptions,
+    ) -> TinPath {
+        let font = self.baseline_font();
+        let mut outlines = TinPath::new();
+        for glyph in self.glyphs_on_path(message, path, options) {
+            let (sin, cos) = glyph.angle.sin_cos();
+            let outline = font
+                .outline(&glyph.character.to_string(), 0.0, 0.0)
+                .map_points(|x, y| (glyph.x + x * cos - y * sin, glyph.y + x * sin + y * cos));
+            outlines.append(&outline);
+        }
+        outlines
+    }
+
+    /// A copy of the font that draws from the start of its baseline.
+    pub(crate) fn baseline_font(&self) -> TinFont {
+        let mut font = self.clone();
+        font.horizontal_alignment = TinFontHorizontalAlignment::Left;
+        font.vertical_alignment = TinFontVerticalAlignment::Baseline;
+        font
+    }
+}
@@ -0,0 +1,86 @@
+use ab_glyph::{point, Font, OutlineCurve, Point, ScaleFont};
+
+use crate::{
+    point::{TPoint, TinPoint},
+    shapes::TinPath,
+    text::{TinFont, TinTextLayout},
+    Double,
+};
+
+impl TinFont {
+    /**
+     Converts a single line of text into vector outlines, placed around an anchor point the same way
+     `draw` places it. The result can be filled, stroked, distorted with `TinPath::map_points`,
+     or exported with `TinPath::to_svg_path_data`.
+    */
+    pub fn outline(&self, message: &str, x: Double, y: Double) -> TinPath {
+        let (positions, line_width) = self.glyph_positions(message);
+        let (dx, dy) = self.anchor_offset(line_width);
+        let mut path = TinPath::new();
+        for (id, pen_x) in positions {
+            self.add_glyph_outline(&mut path, id, x + dx + pen_x as Double, y + dy);
+        }
+        path
+    }
+
+    /// Appends one glyph's contours, with its origin on the baseline at (x, y).
+    fn add_glyph_outline(&self, path: &mut TinPath, id: ab_glyph::GlyphId, x: Double, y: Double) {
+        let outline = match self.font.outline(id) {
+            Some(outline) => outline,
+            None => return,
+        };
+        // Outlines are in unscaled font units with y pointing up, like drawing coordinates.
+        let scaled = self.font.as_scaled(self.get_px_scale());
+        let (h_factor, v_factor) = (scaled.h_scale_factor() as Double, scaled.v_scale_factor() as Double);
+        let place = |p: &Point| {
+            TinPoint::from_coords(
+                x + p.x as Double * h_factor,
+                y + p.y as Double * v_factor,
+            )
+        };
+
+        // A curve that doesn't start where the last one ended begins a new contour.
+        let mut last_end: Option<Point> = None;
+        for curve in &outline.curves {
+            let start = match curve {
+                OutlineCurve::Line(p0, _) | OutlineCurve::Quad(p0, _, _) | OutlineCurve::Cubic(p0, ..) => *p0,
+            };
+            if last_end != Some(start) {
+                path.begin_contour();
+                let p = place(&start);
+                path.vertex(p.get_x(), p.get_y());
+            }
+            match curve {
+                OutlineCurve::Line(_, p1) => {
+                    let p = place(p1);
+                    path.vertex(p.get_x(), p.get_y());
+                    last_end = Some(*p1);
+                }
+                OutlineCurve::Quad(p0, p1, p2) => {
+                    // A quadratic curve is the cubic curve with controls 2/3 of the way to its control point.
+                    let toward = |a: &Point, b: &Point| {
+                        point(a.x + (b.x - a.x) * 2.0 / 3.0, a.y + (b.y - a.y) * 2.0 / 3.0)
+                    };
+                    let (control1, control2) = (toward(p0, p1), toward(p2, p1));
+                    path.add_curve(&place(p2), &place(&control1), &place(&control2));
+                    last_end = Some(*p2);
+                }
+                OutlineCurve::Cubic(_, p1, p2, p3) => {
+                    path.add_curve(&place(p3), &place(p1), &place(p2));
+                    last_end = Some(*p3);
+                }
+            }
+        }
+    }
+}
+
+impl TinTextLayout {
+    /// Every run of the layout as vector outlines, in one path.
+    pub fn to_path(&self) -> TinPath {
+        let mut path = TinPath::new();
+        for run in &self.runs {
+            path.append(&self.font.outline(&run.text, run.x, run.baseline_y));
+        }
+        path
+    }
+}
@@ -0,0 +1,120 @@
+use crate::{
+    context::{get_tin, get_tin_mut},
+    Double, UInt, ULong,
+};
+
+/// Most fixed updates run in one frame. A frame that falls further behind drops the rest, instead of slowing down every frame after it.
+pub const MAX_FIXED_UPDATES_PER_FRAME: UInt = 8;
+
+/// Timing of the current frame.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct TinFrameTime {
+    /// Seconds since the previous frame.
+    pub delta: Double,
+    /// Seconds since the first frame.
+    pub elapsed: Double,
+    /// The current frame, counting from 1 for the first update.
+    pub frame_index: ULong,
+    /**
+     With a fixed timestep, how far the time is past the last fixed update, as a fraction of a step from 0 to 1.
+     Drawing state blended between its last two fixed updates by this amount moves smoothly
+     even when the frame rate and the fixed rate don't match. Always 0 without a fixed timestep.
+    */
+    pub fixed_alpha: Double,
+}
+
+/**
+ Keeps the frame time, and counts how many fixed updates are due each frame.
+ Frame time left over from fixed updates carries over to the next frame, so on average
+ they run at their own rate whatever the frame rate is.
+*/
+#[derive(Debug, Default)]
+pub(crate) struct TinFrameClock {
+    time: TinFrameTime,
+    fixed_step: Option<Double>,
+    accumulator: Double,
+    /// What the last `tick` returned, for windows with their own scene that run the same steps.
+    last_steps: (UInt, Double),
+}
+
+impl TinFrameClock {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get_time(&self) -> TinFrameTime {
+        self.time
+    }
+
+    pub fn get_fixed_step(&self) -> Option<Double> {
+        self.fixed_step
+    }
+
+    /// Seconds between fixed updates, or `None` to turn them off. Steps that aren't positive turn them off too.
+    pub fn set_fixed_step(&mut self, step: Option<Double>) {
+        self.fixed_step = step.filter(|step| *step > 0.0);
+        self.accumulator = 0.0;
+        self.time.fixed_alpha = 0.0;
+    }
+
+    /// Start a new frame. Returns how many fixed updates are due, and the step each one covers.
+    pub fn tick(&mut self, delta: Double, frame_index: ULong) -> (UInt, Double) {
+        self.last_steps = self.advance(delta, frame_index);
+        self.last_steps
+    }
+
+    /// The fixed updates due in the current frame and their step, as the last `tick` returned.
+    pub fn get_last_steps(&self) -> (UInt, Double) {
+        self.last_steps
+    }
+
+    fn advance(&mut self, delta: Double, frame_index: ULong) -> (UInt, Double) {
+        let delta = delta.max(0.0);
+        self.time.delta = delta;
+        self.time.elapsed += delta;
+        self.time.frame_index = frame_index;
+
+        let step = match self.fixed_step {
+            Some(step) => step,
+            None => return (0, 0.0),
+        };
+        self.accumulator += delta;
+        let mut steps = 0;
+        while self.accumulator >= step && steps < MAX_FIXED_UPDATES_PER_FRAME {
+            self.accumulator -= step;
+            steps += 1;
+        }
+        if steps == MAX_FIXED_UPDATES_PER_FRAME {
+            self.accumulator = self.accumulator.min(step);
+        }
+        self.time.fixed_alpha = (self.accumulator / step).min(1.0);
+        (steps, step)
+    }
+}
+
+// MARK: - Global frame time
+
+/// Timing of the current frame.
+pub fn frame_time() -> TinFrameTime {
+    get_tin().clock.get_time()
+}
+
+/// Seconds since the previous frame. Multiply speeds by this to move at the same rate whatever the frame rate.
+pub fn delta_time() -> Double {
+    get_tin().clock.get_time().delta
+}
+
+/// Seconds since the first frame.
+pub fn elapsed_time() -> Double {
+    get_tin().clock.get_time().elapsed
+}
+
+/// Seconds between calls to `TScene::fixed_update`, or `None` when it isn't called.
+pub fn fixed_timestep() -> Option<Double> {
+    get_tin().clock.get_fixed_step()
+}
+
+/// Call `TScene::fixed_update` every `step` seconds, or never for `None`.
+pub fn set_fixed_timestep(step: Option<Double>) {
+    get_tin_mut().clock.set_fixed_step(step);
+}
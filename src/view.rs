@@ -1,16 +1,64 @@
-use crate::{frame::TinFrame, UInt};
+use crate::{
+    context::{get_tin, get_tin_mut},
+    frame::TinFrame,
+    Double, Int, UInt,
+};
 
+/// Where the window opens on screen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TinWindowPosition {
+    /// Wherever the system puts it.
+    Default,
+    /// The top left corner of the window at this position on the screen, in pixels.
+    At(Int, Int),
+    /// The top left corner of the screen the window opens on.
+    TopLeft,
+    /// The middle of the screen the window opens on.
+    Centered,
+}
+
+impl TinWindowPosition {
+    /**
+     The window's top left corner in screen pixels, for a monitor at `monitor_position` with `monitor_size`,
+     or `None` to leave the window where it is.
+    */
+    pub(crate) fn resolve(
+        &self,
+        monitor_position: (Int, Int),
+        monitor_size: (UInt, UInt),
+        window_size: (UInt, UInt),
+    ) -> Option<(Int, Int)> {
+        match self {
+            TinWindowPosition::Default => None,
+            TinWindowPosition::At(x, y) => Some((*x, *y)),
+            TinWindowPosition::TopLeft => Some(monitor_position),
+            TinWindowPosition::Centered => Some((
+                monitor_position.0 + (monitor_size.0 as Int - window_size.0 as Int) / 2,
+                monitor_position.1 + (monitor_size.1 as Int - window_size.1 as Int) / 2,
+            )),
+        }
+    }
+}
+
+/// The window a scene is shown in, and how it opens. Backends without a window only use the size.
+#[derive(Debug, Clone)]
 pub struct TinView {
-    title: &'static str,
+    title: String,
     frame: TinFrame,
+    resizable: bool,
+    fullscreen: bool,
+    decorations: bool,
+    position: TinWindowPosition,
+    always_on_top: bool,
+    cursor_visible: bool,
 }
 
 pub trait TView {
     /// TODO: Document this method.
-    fn new(title: &'static str, frame: TinFrame) -> Self;
+    fn new(title: &str, frame: TinFrame) -> Self;
 
     /// TODO: Document this method.
-    fn from_dimensions(title: &'static str, width: UInt, height: UInt) -> Self;
+    fn from_dimensions(title: &str, width: UInt, height: UInt) -> Self;
 
     /// TODO: Document this method.
     fn get_frame(&self) -> &TinFrame;
@@ -19,11 +67,20 @@ pub trait TView {
 }
 
 impl TView for TinView {
-    fn new(title: &'static str, frame: TinFrame) -> TinView {
-        Self { title, frame }
+    fn new(title: &str, frame: TinFrame) -> TinView {
+        Self {
+            title: String::from(title),
+            frame,
+            resizable: true,
+            fullscreen: false,
+            decorations: true,
+            position: TinWindowPosition::Default,
+            always_on_top: false,
+            cursor_visible: true,
+        }
     }
 
-    fn from_dimensions(title: &'static str, width: UInt, height: UInt) -> Self {
+    fn from_dimensions(title: &str, width: UInt, height: UInt) -> Self {
         //let newFrame = NSRect(x: 0.0, y: 0.0, width: width, height: height);
         let frame = TinFrame::new(width, height);
         Self::new(title, frame)
@@ -34,22 +91,167 @@ impl TView for TinView {
     }
 
     fn get_title(&self) -> &str {
-        self.title
+        &self.title
     }
 }
 
 impl TinView {
     // MARK: - initializers
 
+    /// Whether the user can resize the window. On by default.
+    pub fn resizable(mut self, resizable: bool) -> Self {
+        self.resizable = resizable;
+        self
+    }
+
+    /// Whether the window covers the whole screen it opens on. Off by default.
+    pub fn fullscreen(mut self, fullscreen: bool) -> Self {
+        self.fullscreen = fullscreen;
+        self
+    }
+
+    /// Whether the window has a title bar and borders. On by default.
+    pub fn decorations(mut self, decorations: bool) -> Self {
+        self.decorations = decorations;
+        self
+    }
+
+    pub fn position(mut self, position: TinWindowPosition) -> Self {
+        self.position = position;
+        self
+    }
+
+    /// Whether the window stays above other windows. Off by default.
+    pub fn always_on_top(mut self, always_on_top: bool) -> Self {
+        self.always_on_top = always_on_top;
+        self
+    }
+
+    /// Whether the mouse cursor shows over the window. On by default.
+    pub fn cursor_visible(mut self, cursor_visible: bool) -> Self {
+        self.cursor_visible = cursor_visible;
+        self
+    }
+
     /// move the window to the top, left corner of the current screen
     pub fn move_window_to_topleft(&mut self) {
-        todo!(
-            "Moving window to top left of current screen through this method is not yet supported."
-        );
+        self.position = TinWindowPosition::TopLeft;
     }
 
     /// move the window to center it in the current screen
     pub fn move_window_to_center(&mut self) {
-        todo!("Moving window to center of screen through this method is not yet supported.");
+        self.position = TinWindowPosition::Centered;
+    }
+
+    pub fn is_resizable(&self) -> bool {
+        self.resizable
+    }
+
+    pub fn is_fullscreen(&self) -> bool {
+        self.fullscreen
+    }
+
+    pub fn has_decorations(&self) -> bool {
+        self.decorations
     }
+
+    pub fn get_position(&self) -> TinWindowPosition {
+        self.position
+    }
+
+    pub fn is_always_on_top(&self) -> bool {
+        self.always_on_top
+    }
+
+    pub fn is_cursor_visible(&self) -> bool {
+        self.cursor_visible
+    }
+
+    /// Record the size the window actually has, after the user or the system resized it.
+    pub(crate) fn set_frame(&mut self, frame: TinFrame) {
+        self.frame = frame;
+    }
+}
+
+/// A change to a window asked for while the app runs. Backends carry them out between frames.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum TinWindowRequest {
+    Title(String),
+    Size(UInt, UInt),
+    Position(TinWindowPosition),
+    Fullscreen(bool),
+    CursorVisible(bool),
+    AlwaysOnTop(bool),
+}
+
+/// Ask for a change to the window being handled or drawn. Only the main window's settings are kept in the view.
+fn request(change: TinWindowRequest) {
+    let mut tin = get_tin_mut();
+    let window = tin.current_window;
+    if window.is_main() {
+        match &change {
+            TinWindowRequest::Title(title) => tin.view.title = title.clone(),
+            TinWindowRequest::Size(width, height) => tin.view.frame = TinFrame::new(*width, *height),
+            TinWindowRequest::Position(position) => tin.view.position = *position,
+            TinWindowRequest::Fullscreen(fullscreen) => tin.view.fullscreen = *fullscreen,
+            TinWindowRequest::CursorVisible(visible) => tin.view.cursor_visible = *visible,
+            TinWindowRequest::AlwaysOnTop(always_on_top) => tin.view.always_on_top = *always_on_top,
+        }
+    }
+    tin.window_requests.push((window, change));
+}
+
+// MARK: - Global window state
+
+/// Width of the drawing area in points, following the window as it is resized.
+pub fn width() -> Double {
+    get_tin().width
+}
+
+/// Height of the drawing area in points, following the window as it is resized.
+pub fn height() -> Double {
+    get_tin().height
+}
+
+/**
+ Pixels per point of the screen the window is on, such as 2.0 on most HiDPI screens.
+ Drawing coordinates are always in points. Multiply by this to get the size of the backing pixels.
+*/
+pub fn pixel_density() -> Double {
+    get_tin().pixel_density
+}
+
+pub fn window_title() -> String {
+    get_tin().view.title.clone()
+}
+
+/// The size last asked for, in points.
+pub fn window_size() -> (UInt, UInt) {
+    let frame = get_tin().view.frame;
+    (frame.get_width(), frame.get_height())
+}
+
+pub fn set_window_title(title: &str) {
+    request(TinWindowRequest::Title(String::from(title)));
+}
+
+/// Resize the window's drawing area, in points.
+pub fn set_window_size(width: UInt, height: UInt) {
+    request(TinWindowRequest::Size(width, height));
+}
+
+pub fn set_window_position(position: TinWindowPosition) {
+    request(TinWindowRequest::Position(position));
+}
+
+pub fn set_fullscreen(fullscreen: bool) {
+    request(TinWindowRequest::Fullscreen(fullscreen));
+}
+
+pub fn set_cursor_visible(visible: bool) {
+    request(TinWindowRequest::CursorVisible(visible));
+}
+
+pub fn set_always_on_top(always_on_top: bool) {
+    request(TinWindowRequest::AlwaysOnTop(always_on_top));
 }
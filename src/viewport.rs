@@ -0,0 +1,155 @@
+use crate::{
+    color::TinColor,
+    context::{get_tin, get_tin_mut},
+    draw::DrawCall,
+    frame::TinFrame,
+    point::{TPoint, TinPoint},
+    shapes::TinRect,
+    space::{TinCoordinateSpace, TinSpaceMapping},
+    Double, UInt,
+};
+
+/**
+ A rectangular part of the window with its own coordinate space, drawn into between `begin_viewport`
+ and `end_viewport`. What is drawn is clipped to it unless clipping is turned off, and it can clear
+ itself to a background color first.
+*/
+#[derive(Debug, Clone)]
+pub struct TinViewport {
+    rect: TinRect,
+    space: TinCoordinateSpace,
+    background: Option<TinColor>,
+    clip: bool,
+}
+
+impl TinViewport {
+    /// A viewport covering `rect`, given in the window's drawing coordinates. It draws in centered pixels and clips.
+    pub fn new(rect: TinRect) -> Self {
+        Self {
+            rect,
+            space: TinCoordinateSpace::CenteredPixels,
+            background: None,
+            clip: true,
+        }
+    }
+
+    /// A viewport the size of `frame`, with its middle at x, y in the window's drawing coordinates.
+    pub fn from_frame(x: Double, y: Double, frame: &TinFrame) -> Self {
+        Self::new(TinRect::from_dimensions(
+            x,
+            y,
+            frame.get_width() as Double,
+            frame.get_height() as Double,
+        ))
+    }
+
+    /**
+     The window split into `columns` by `rows` viewports with `gap` points between them,
+     in reading order: left to right, then top to bottom.
+    */
+    pub fn grid(columns: UInt, rows: UInt, gap: Double) -> Vec<Self> {
+        let (mapping, width, height) = {
+            let tin = get_tin();
+            (tin.space_mapping(), tin.width, tin.height)
+        };
+        grid_rects(width, height, columns, rows, gap)
+            .iter()
+            .map(|rect| Self::new(mapping.inverse_rect(rect)))
+            .collect()
+    }
+
+    // MARK: - initializers
+
+    /// The coordinate space drawing inside uses, measured from the viewport's own corner or middle.
+    pub fn coordinate_space(mut self, space: TinCoordinateSpace) -> Self {
+        self.space = space;
+        self
+    }
+
+    /// Fill the viewport with a color when drawing into it begins.
+    pub fn background(mut self, color: TinColor) -> Self {
+        self.background = Some(color);
+        self
+    }
+
+    /// Whether drawing is cut off at the viewport's edges. On by default.
+    pub fn clip(mut self, clip: bool) -> Self {
+        self.clip = clip;
+        self
+    }
+
+    pub fn get_rect(&self) -> &TinRect {
+        &self.rect
+    }
+
+    pub fn get_coordinate_space(&self) -> TinCoordinateSpace {
+        self.space
+    }
+
+    pub fn get_background(&self) -> Option<TinColor> {
+        self.background
+    }
+
+    pub fn is_clipped(&self) -> bool {
+        self.clip
+    }
+
+    /// Whether a point in the window's drawing coordinates, such as the mouse position, is inside.
+    pub fn contains(&self, point: &impl TPoint) -> bool {
+        let mapping = get_tin().space_mapping();
+        let (x, y) = mapping.point(point.get_x(), point.get_y());
+        rect_contains(&mapping.rect(&self.rect), x, y)
+    }
+
+    /// A point in the window's drawing coordinates, such as the mouse position, in the viewport's coordinates.
+    pub fn to_local(&self, point: &impl TPoint) -> TinPoint {
+        let mapping = get_tin().space_mapping();
+        let (x, y) = mapping.point(point.get_x(), point.get_y());
+        let (x, y) = self.local_mapping(&mapping).inverse_point(x, y);
+        TinPoint::from_coords(x, y)
+    }
+
+    /// Maps the viewport's coordinates onto the backend's, given the window's mapping.
+    pub(crate) fn local_mapping(&self, window_mapping: &TinSpaceMapping) -> TinSpaceMapping {
+        TinSpaceMapping::within(self.space, &window_mapping.rect(&self.rect))
+    }
+}
+
+/// Rects laid out as `TinViewport::grid`, from the middle of a window of `width` by `height` with y growing upwards.
+pub(crate) fn grid_rects(width: Double, height: Double, columns: UInt, rows: UInt, gap: Double) -> Vec<TinRect> {
+    if columns == 0 || rows == 0 {
+        return Vec::new();
+    }
+    let cell_width = ((width - gap * (columns - 1) as Double) / columns as Double).max(0.0);
+    let cell_height = ((height - gap * (rows - 1) as Double) / rows as Double).max(0.0);
+    let mut rects = Vec::with_capacity((columns * rows) as usize);
+    for row in 0..rows {
+        for column in 0..columns {
+            let x = -width / 2.0 + column as Double * (cell_width + gap) + cell_width / 2.0;
+            let y = height / 2.0 - row as Double * (cell_height + gap) - cell_height / 2.0;
+            rects.push(TinRect::from_dimensions(x, y, cell_width, cell_height));
+        }
+    }
+    rects
+}
+
+pub(crate) fn rect_contains(rect: &TinRect, x: Double, y: Double) -> bool {
+    (x - rect.center.get_x()).abs() <= rect.get_width() / 2.0
+        && (y - rect.center.get_y()).abs() <= rect.get_height() / 2.0
+}
+
+// MARK: - Global viewport
+
+/// Draw inside the viewport, in its coordinate space, until `end_viewport` or the end of the frame.
+pub fn begin_viewport(viewport: &TinViewport) {
+    let mut tin = get_tin_mut();
+    tin.viewport = Some(viewport.clone());
+    tin.current_calls().push_back(DrawCall::Viewport(Some(viewport.clone())));
+}
+
+/// Go back to drawing over the whole window.
+pub fn end_viewport() {
+    let mut tin = get_tin_mut();
+    tin.viewport = None;
+    tin.current_calls().push_back(DrawCall::Viewport(None));
+}
@@ -0,0 +1,63 @@
+use crate::{context::get_tin, scene::TScene, view::TinView, UInt};
+
+/**
+ Identifies one of the app's windows. The window given to `Tin::view` is `TinWindowId::MAIN`,
+ and the ones added with `Tin::window` or `Tin::window_with_scene` follow it in order.
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct TinWindowId(UInt);
+
+impl TinWindowId {
+    pub const MAIN: TinWindowId = TinWindowId(0);
+
+    pub(crate) fn new(index: UInt) -> Self {
+        TinWindowId(index)
+    }
+
+    /// 0 for the main window, then 1, 2, ... in the order windows were added.
+    pub fn get_index(&self) -> UInt {
+        self.0
+    }
+
+    pub fn is_main(&self) -> bool {
+        *self == TinWindowId::MAIN
+    }
+}
+
+impl Default for TinWindowId {
+    fn default() -> Self {
+        TinWindowId::MAIN
+    }
+}
+
+/// Which scene a window besides the main one shows.
+#[derive(Clone, Copy)]
+pub(crate) enum TinWindowScene {
+    /// The app's scene, drawn again for this window.
+    Shared,
+    /// A scene of its own, set up when the window opens.
+    Own(fn() -> Box<dyn TScene>),
+}
+
+/// A window besides the main one, as added to the app before it runs.
+#[derive(Clone)]
+pub(crate) struct TinWindowConfig {
+    pub id: TinWindowId,
+    pub view: TinView,
+    pub scene: TinWindowScene,
+}
+
+// MARK: - Global window identity
+
+/**
+ The window whose event is being handled or whose frame is being drawn. Scenes shared between windows
+ check it in `draw` to decide what to show. Outside of those it is the main window.
+*/
+pub fn current_window() -> TinWindowId {
+    get_tin().current_window
+}
+
+/// How many windows the app opened, the main one included.
+pub fn window_count() -> UInt {
+    get_tin().window_sizes.len() as UInt
+}